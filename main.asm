@@ -0,0 +1,445 @@
+  .text
+  .globl f_0
+f_0:
+  addi sp, sp, -32
+  sw a0, 0(sp)
+.Lf_0_entry_0:
+  j .Lf_0_body_1
+.Lf_0_body_1:
+  lw t0, 0(sp)
+  sw t0, 8(sp)
+  li t0, 0
+  li t1, 19
+  add t0, t0, t1
+  sw t0, 12(sp)
+  li t0, 0
+  lw t1, 12(sp)
+  add t0, t0, t1
+  sw t0, 16(sp)
+  li t0, 0
+  lw t1, 16(sp)
+  xor t0, t0, t1
+  seqz t0, t0
+  sw t0, 20(sp)
+  li t0, 0
+  lw t1, 20(sp)
+  sub t0, t0, t1
+  sw t0, 24(sp)
+  lw t0, 24(sp)
+  sw t0, 4(sp)
+  j .Lf_0_end_3
+.Lf_0_after_return_2:
+  li t0, 0
+  sw t0, 4(sp)
+  j .Lf_0_end_3
+.Lf_0_end_3:
+  lw t0, 4(sp)
+  sw t0, 28(sp)
+  lw a0, 28(sp)
+  addi sp, sp, 32
+  ret
+
+  .text
+  .globl f_1
+f_1:
+  addi sp, sp, -64
+  sw a0, 0(sp)
+  sw a1, 4(sp)
+.Lf_1_entry_0:
+  j .Lf_1_body_1
+.Lf_1_body_1:
+  lw t0, 0(sp)
+  sw t0, 12(sp)
+  lw t0, 4(sp)
+  sw t0, 16(sp)
+  lw t0, 12(sp)
+  sw t0, 24(sp)
+  lw t0, 24(sp)
+  li t1, 5
+  sgt t0, t0, t1
+  sw t0, 28(sp)
+  lw t0, 28(sp)
+  bnez t0, .Lf_1_then_2
+  j .Lf_1_else_3
+.Lf_1_then_2:
+  li t0, 514
+  li t1, 19
+  rem t0, t0, t1
+  sw t0, 32(sp)
+  lw t0, 32(sp)
+  sw t0, 12(sp)
+  li t0, 114
+  li t1, 19
+  div t0, t0, t1
+  sw t0, 36(sp)
+  lw t0, 36(sp)
+  sw t0, 20(sp)
+  lw t0, 16(sp)
+  sw t0, 40(sp)
+  lw t0, 20(sp)
+  sw t0, 44(sp)
+  lw t0, 40(sp)
+  lw t1, 44(sp)
+  mul t0, t0, t1
+  sw t0, 48(sp)
+  lw t0, 48(sp)
+  sw t0, 16(sp)
+  j .Lf_1_body_4
+.Lf_1_else_3:
+  j .Lf_1_body_4
+.Lf_1_body_4:
+  lw t0, 16(sp)
+  sw t0, 52(sp)
+  lw t0, 52(sp)
+  sw t0, 8(sp)
+  j .Lf_1_end_6
+.Lf_1_after_return_5:
+  li t0, 0
+  sw t0, 8(sp)
+  j .Lf_1_end_6
+.Lf_1_end_6:
+  lw t0, 8(sp)
+  sw t0, 56(sp)
+  lw a0, 56(sp)
+  addi sp, sp, 64
+  ret
+
+  .text
+  .globl f_2
+f_2:
+  addi sp, sp, -32
+.Lf_2_entry_0:
+  j .Lf_2_body_1
+.Lf_2_body_1:
+  li t0, 10
+  sw t0, 4(sp)
+  j .Lf_2_while_entry_2
+.Lf_2_while_entry_2:
+  lw t0, 4(sp)
+  sw t0, 8(sp)
+  lw t0, 8(sp)
+  li t1, 0
+  sgt t0, t0, t1
+  sw t0, 12(sp)
+  lw t0, 12(sp)
+  bnez t0, .Lf_2_while_body_3
+  j .Lf_2_body_4
+.Lf_2_while_body_3:
+  lw t0, 4(sp)
+  sw t0, 16(sp)
+  lw t0, 16(sp)
+  li t1, 1
+  sub t0, t0, t1
+  sw t0, 20(sp)
+  lw t0, 20(sp)
+  sw t0, 4(sp)
+  j .Lf_2_while_entry_2
+.Lf_2_body_4:
+  lw t0, 4(sp)
+  sw t0, 24(sp)
+  lw t0, 24(sp)
+  sw t0, 0(sp)
+  j .Lf_2_end_6
+.Lf_2_after_return_5:
+  li t0, 0
+  sw t0, 0(sp)
+  j .Lf_2_end_6
+.Lf_2_end_6:
+  lw t0, 0(sp)
+  sw t0, 28(sp)
+  lw a0, 28(sp)
+  addi sp, sp, 32
+  ret
+
+  .text
+  .globl f_3
+f_3:
+  addi sp, sp, -144
+  sw a0, 0(sp)
+  sw a1, 4(sp)
+  sw a2, 8(sp)
+  sw a3, 12(sp)
+  sw a4, 16(sp)
+  sw a5, 20(sp)
+  sw a6, 24(sp)
+  sw a7, 28(sp)
+.Lf_3_entry_0:
+  j .Lf_3_body_1
+.Lf_3_body_1:
+  lw t0, 0(sp)
+  sw t0, 36(sp)
+  lw t0, 4(sp)
+  sw t0, 40(sp)
+  lw t0, 8(sp)
+  sw t0, 44(sp)
+  lw t0, 12(sp)
+  sw t0, 48(sp)
+  lw t0, 16(sp)
+  sw t0, 52(sp)
+  lw t0, 20(sp)
+  sw t0, 56(sp)
+  lw t0, 24(sp)
+  sw t0, 60(sp)
+  lw t0, 28(sp)
+  sw t0, 64(sp)
+  lw t0, 144(sp)
+  sw t0, 68(sp)
+  lw t0, 36(sp)
+  sw t0, 72(sp)
+  lw t0, 40(sp)
+  sw t0, 76(sp)
+  lw t0, 72(sp)
+  lw t1, 76(sp)
+  add t0, t0, t1
+  sw t0, 80(sp)
+  lw t0, 44(sp)
+  sw t0, 84(sp)
+  lw t0, 80(sp)
+  lw t1, 84(sp)
+  add t0, t0, t1
+  sw t0, 88(sp)
+  lw t0, 48(sp)
+  sw t0, 92(sp)
+  lw t0, 88(sp)
+  lw t1, 92(sp)
+  add t0, t0, t1
+  sw t0, 96(sp)
+  lw t0, 52(sp)
+  sw t0, 100(sp)
+  lw t0, 96(sp)
+  lw t1, 100(sp)
+  add t0, t0, t1
+  sw t0, 104(sp)
+  lw t0, 56(sp)
+  sw t0, 108(sp)
+  lw t0, 104(sp)
+  lw t1, 108(sp)
+  add t0, t0, t1
+  sw t0, 112(sp)
+  lw t0, 60(sp)
+  sw t0, 116(sp)
+  lw t0, 112(sp)
+  lw t1, 116(sp)
+  add t0, t0, t1
+  sw t0, 120(sp)
+  lw t0, 64(sp)
+  sw t0, 124(sp)
+  lw t0, 120(sp)
+  lw t1, 124(sp)
+  add t0, t0, t1
+  sw t0, 128(sp)
+  lw t0, 68(sp)
+  sw t0, 132(sp)
+  lw t0, 128(sp)
+  lw t1, 132(sp)
+  add t0, t0, t1
+  sw t0, 136(sp)
+  lw t0, 136(sp)
+  sw t0, 32(sp)
+  j .Lf_3_end_3
+.Lf_3_after_return_2:
+  li t0, 0
+  sw t0, 32(sp)
+  j .Lf_3_end_3
+.Lf_3_end_3:
+  lw t0, 32(sp)
+  sw t0, 140(sp)
+  lw a0, 140(sp)
+  addi sp, sp, 144
+  ret
+
+  .text
+  .globl main
+main:
+  addi sp, sp, -192
+  sw ra, 188(sp)
+.Lmain_entry_0:
+  j .Lmain_body_1
+.Lmain_body_1:
+  li t0, 1
+  bnez t0, .Lmain_logic_true_4
+  j .Lmain_lor_rhs_2
+.Lmain_lor_rhs_2:
+  li t0, 1
+  bnez t0, .Lmain_land_rhs_3
+  j .Lmain_logic_false_5
+.Lmain_land_rhs_3:
+  li t0, 0
+  bnez t0, .Lmain_logic_true_4
+  j .Lmain_logic_false_5
+.Lmain_logic_true_4:
+  li t0, 1
+  sw t0, 8(sp)
+  j .Lmain_logic_end_6
+.Lmain_logic_false_5:
+  li t0, 0
+  sw t0, 8(sp)
+  j .Lmain_logic_end_6
+.Lmain_logic_end_6:
+  lw t0, 8(sp)
+  sw t0, 44(sp)
+  lw t0, 44(sp)
+  sw t0, 12(sp)
+  li t0, 1
+  li t1, 4
+  xor t0, t0, t1
+  snez t0, t0
+  sw t0, 48(sp)
+  li t0, 1
+  lw t1, 48(sp)
+  xor t0, t0, t1
+  seqz t0, t0
+  sw t0, 52(sp)
+  lw t0, 52(sp)
+  sw t0, 16(sp)
+  li t0, 4
+  li t1, 5
+  sgt t0, t0, t1
+  sw t0, 56(sp)
+  lw t0, 56(sp)
+  li t1, 1
+  slt t0, t0, t1
+  seqz t0, t0
+  sw t0, 60(sp)
+  lw t0, 60(sp)
+  li t1, 4
+  sgt t0, t0, t1
+  seqz t0, t0
+  sw t0, 64(sp)
+  li t0, 11
+  lw t1, 64(sp)
+  sgt t0, t0, t1
+  sw t0, 68(sp)
+  lw t0, 68(sp)
+  sw t0, 20(sp)
+  li t0, 514
+  li t1, 1919
+  sub t0, t0, t1
+  sw t0, 72(sp)
+  li t0, 114
+  li t1, 80
+  rem t0, t0, t1
+  sw t0, 76(sp)
+  li t0, 114
+  lw t1, 76(sp)
+  div t0, t0, t1
+  sw t0, 80(sp)
+  lw t0, 80(sp)
+  li t1, 19
+  mul t0, t0, t1
+  sw t0, 84(sp)
+  lw t0, 72(sp)
+  lw t1, 84(sp)
+  add t0, t0, t1
+  sw t0, 88(sp)
+  lw t0, 88(sp)
+  sw t0, 24(sp)
+  li t0, 19
+  mv a0, t0
+  call f_0
+  sw a0, 92(sp)
+  lw t0, 92(sp)
+  sw t0, 28(sp)
+  li t0, 114
+  li t1, 19
+  div t0, t0, t1
+  sw t0, 96(sp)
+  lw t0, 96(sp)
+  sw t0, 32(sp)
+  lw t0, 32(sp)
+  sw t0, 100(sp)
+  lw t0, 24(sp)
+  sw t0, 104(sp)
+  lw t0, 100(sp)
+  mv a0, t0
+  lw t0, 104(sp)
+  mv a1, t0
+  call f_1
+  sw a0, 108(sp)
+  lw t0, 108(sp)
+  sw t0, 32(sp)
+  lw t0, 32(sp)
+  sw t0, 112(sp)
+  lw t0, 112(sp)
+  li t1, 5
+  sgt t0, t0, t1
+  sw t0, 116(sp)
+  lw t0, 116(sp)
+  bnez t0, .Lmain_then_7
+  j .Lmain_else_8
+.Lmain_then_7:
+  lw t0, 24(sp)
+  sw t0, 120(sp)
+  lw t0, 28(sp)
+  sw t0, 124(sp)
+  lw t0, 120(sp)
+  lw t1, 124(sp)
+  mul t0, t0, t1
+  sw t0, 128(sp)
+  lw t0, 128(sp)
+  sw t0, 36(sp)
+  j .Lmain_body_9
+.Lmain_else_8:
+  lw t0, 24(sp)
+  sw t0, 132(sp)
+  lw t0, 32(sp)
+  sw t0, 136(sp)
+  lw t0, 132(sp)
+  lw t1, 136(sp)
+  mul t0, t0, t1
+  sw t0, 140(sp)
+  lw t0, 140(sp)
+  sw t0, 40(sp)
+  j .Lmain_body_9
+.Lmain_body_9:
+  call f_2
+  sw a0, 144(sp)
+  lw t0, 144(sp)
+  sw t0, 24(sp)
+  lw t0, 12(sp)
+  sw t0, 148(sp)
+  lw t0, 16(sp)
+  sw t0, 152(sp)
+  lw t0, 20(sp)
+  sw t0, 156(sp)
+  lw t0, 24(sp)
+  sw t0, 160(sp)
+  lw t0, 28(sp)
+  sw t0, 164(sp)
+  lw t0, 32(sp)
+  sw t0, 168(sp)
+  lw t0, 148(sp)
+  mv a0, t0
+  lw t0, 152(sp)
+  mv a1, t0
+  lw t0, 156(sp)
+  mv a2, t0
+  lw t0, 160(sp)
+  mv a3, t0
+  lw t0, 164(sp)
+  mv a4, t0
+  lw t0, 168(sp)
+  mv a5, t0
+  li t0, 1
+  mv a6, t0
+  li t0, 1
+  mv a7, t0
+  li t0, 4
+  sw t0, 0(sp)
+  call f_3
+  sw a0, 172(sp)
+  lw t0, 172(sp)
+  sw t0, 4(sp)
+  j .Lmain_end_11
+.Lmain_after_return_10:
+  li t0, 0
+  sw t0, 4(sp)
+  j .Lmain_end_11
+.Lmain_end_11:
+  lw t0, 4(sp)
+  sw t0, 176(sp)
+  lw a0, 176(sp)
+  lw ra, 188(sp)
+  addi sp, sp, 192
+  ret
+
@@ -1,29 +1,44 @@
+use crate::source::Span;
 use crate::ast::var::LVal;
 use crate::ast::func::FuncRParams;
 
 #[derive(Debug)]
 pub struct Exp {
     pub exp: LOrExp,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct ConstExp {
+    pub exp: AddExp,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub enum PrimaryExp {
     Exp(Box<Exp>),
     LVal(LVal),
-    Number(i32),
+    Number(Number),
+}
+
+#[derive(Debug)]
+pub struct Number {
+    /// The value as written, which may not fit in an `int`.
+    pub value: i64,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub enum UnaryExp {
     Primary(PrimaryExp),
-    Func(String, Option<FuncRParams>),
+    Func(String, Option<FuncRParams>, Span),
     Unary(UnaryOp, Box<UnaryExp>),
 }
 
 #[derive(Debug)]
 pub enum MulExp {
     Unary(UnaryExp),
-    MulUnary(Box<MulExp>, MulOp, UnaryExp),
+    MulUnary(Box<MulExp>, MulOp, UnaryExp, Span),
 }
 
 #[derive(Debug)]
@@ -1,4 +1,5 @@
-use crate::ast::exp::Exp;
+use crate::source::Span;
+use crate::ast::exp::{ConstExp, Exp};
 use crate::ast::var::BType;
 use crate::ast::stmt::Block;
 
@@ -7,12 +8,15 @@ pub struct FuncDef {
     pub func_type: FuncType,
     pub ident: String,
     pub params: Option<FuncFParams>,
-    pub block: Block,
+    /// `None` for prototypes, which only declare the function.
+    pub block: Option<Block>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub enum FuncType {
-    Int
+    Int,
+    Void,
 }
 
 #[derive(Debug)]
@@ -24,6 +28,9 @@ pub struct FuncFParams {
 pub struct FuncFParam {
     pub param_type: BType,
     pub id: String,
+    /// `Some` if the parameter is an array, holding all dimensions except the first one.
+    pub dims: Option<Vec<ConstExp>>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
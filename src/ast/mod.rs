@@ -10,5 +10,13 @@ pub use func::*;
 
 #[derive(Debug)]
 pub struct CompUnit {
-    pub func_defs: Vec<FuncDef>,
+    pub items: Vec<GlobalItem>,
 }
+
+#[derive(Debug)]
+pub enum GlobalItem {
+    ConstDecl(ConstDecl),
+    Decl(Decl),
+    FuncDef(FuncDef),
+}
+
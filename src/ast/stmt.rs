@@ -1,23 +1,29 @@
+use crate::source::Span;
 use crate::ast::exp::Exp;
-use crate::ast::var::{Decl, LVal};
+use crate::ast::var::{ConstDecl, Decl, LVal};
 
 #[derive(Debug)]
 pub struct Block {
     pub items: Vec<BlockItem>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub enum BlockItem {
+    ConstDecl(ConstDecl),
     Decl(Decl),
-    Stmt(Stmt),
+    Stmt(Box<Stmt>),
 }
 
 #[derive(Debug)]
 pub enum Stmt {
     Assign(Assign),
+    Exp(ExpStmt),
     Sblock(Sblock),
     If(If),
     While(While),
+    Break(Break),
+    Continue(Continue),
     Return(Return),
 }
 
@@ -25,28 +31,49 @@ pub enum Stmt {
 pub struct Assign {
     pub lval: LVal,
     pub exp: Exp,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct ExpStmt {
+    pub exp: Option<Exp>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct Sblock {
     pub block: Block,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct If {
     pub cond: Exp,
-    pub then_block: Block,
-    pub else_block: Option<Block>,
+    pub then_stmt: Box<Stmt>,
+    pub else_stmt: Option<Box<Stmt>>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct While {
     pub cond: Exp,
-    pub block: Block,
+    pub body: Box<Stmt>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct Break {
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct Continue {
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct Return {
-    pub exp: Exp,
+    pub exp: Option<Exp>,
+    pub span: Span,
 }
 
@@ -1,25 +1,53 @@
-use crate::ast::Exp;
+use crate::source::Span;
+use crate::ast::{Exp, ConstExp};
+
+#[derive(Debug)]
+pub struct ConstDecl {
+    pub decl_type: BType,
+    pub defs: Vec<ConstDef>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct ConstDef {
+    pub id: String,
+    pub dims: Vec<ConstExp>,
+    pub val: ConstInitVal,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ConstInitVal {
+    Exp(ConstExp),
+    List(Vec<ConstInitVal>),
+}
 
 #[derive(Debug)]
 pub struct Decl {
     pub decl_type: BType,
     pub defs: Vec<Def>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct Def {
     pub id: String,
+    pub dims: Vec<ConstExp>,
     pub val: Option<InitVal>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
-pub struct InitVal {
-    pub exp: Exp,
+pub enum InitVal {
+    Exp(Exp),
+    List(Vec<InitVal>),
 }
 
 #[derive(Debug)]
 pub struct LVal {
     pub id: String,
+    pub indices: Vec<Exp>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
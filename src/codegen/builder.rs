@@ -1,41 +1,111 @@
 use super::func::FunctionInfo;
 use std::fs::File;
 use std::io::{Result, Write};
+
+/// Assembly builder.
 pub struct AsmBuilder<'f> {
   f: &'f mut File,
   temp: &'static str,
 }
+
 impl<'f> AsmBuilder<'f> {
+  /// Creates a new assembly builder.
   pub fn new(f: &'f mut File, temp: &'static str) -> Self {
     Self { f, temp }
   }
+
+  /// Generates `li`.
   pub fn li(&mut self, dest: &str, imm: i32) -> Result<()> {
     writeln!(self.f, "  li {dest}, {imm}")
   }
+
+  /// Generates `la`.
+  pub fn la(&mut self, dest: &str, symbol: &str) -> Result<()> {
+    writeln!(self.f, "  la {dest}, {symbol}")
+  }
+
+  /// Generates `mv`.
+  pub fn mv(&mut self, dest: &str, src: &str) -> Result<()> {
+    if dest != src {
+      writeln!(self.f, "  mv {dest}, {src}")
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Generates an instruction with one source operand.
+  pub fn op1(&mut self, op: &str, dest: &str, src: &str) -> Result<()> {
+    writeln!(self.f, "  {op} {dest}, {src}")
+  }
+
+  /// Generates an instruction with two source operands.
+  pub fn op2(&mut self, op: &str, dest: &str, lhs: &str, rhs: &str) -> Result<()> {
+    writeln!(self.f, "  {op} {dest}, {lhs}, {rhs}")
+  }
+
+  /// Generates `addi`, falls back to `add` if the immediate is too large.
   pub fn addi(&mut self, dest: &str, opr: &str, offset: i32) -> Result<()> {
-    if offset >= -2048 && offset <= 2047 {
+    if (-2048..=2047).contains(&offset) {
       writeln!(self.f, "  addi {dest}, {opr}, {offset}")
     } else {
       self.li(self.temp, offset)?;
       writeln!(self.f, "  add {dest}, {opr}, {}", self.temp)
     }
   }
+
+  /// Generates `muli`, which is a pseudo instruction.
+  pub fn muli(&mut self, dest: &str, opr: &str, imm: i32) -> Result<()> {
+    if imm == 0 {
+      self.mv(dest, "x0")
+    } else if imm > 0 && (imm & (imm - 1)) == 0 {
+      writeln!(self.f, "  slli {dest}, {opr}, {}", imm.trailing_zeros())
+    } else {
+      self.li(self.temp, imm)?;
+      self.op2("mul", dest, opr, self.temp)
+    }
+  }
+
+  /// Generates `sw`.
   pub fn sw(&mut self, src: &str, addr: &str, offset: i32) -> Result<()> {
-    if offset >= -2048 && offset <= 2047 {
+    if (-2048..=2047).contains(&offset) {
       writeln!(self.f, "  sw {src}, {offset}({addr})")
     } else {
       self.addi(self.temp, addr, offset)?;
       writeln!(self.f, "  sw {src}, 0({})", self.temp)
     }
   }
+
+  /// Generates `lw`.
   pub fn lw(&mut self, dest: &str, addr: &str, offset: i32) -> Result<()> {
-    if offset >= -2048 && offset <= 2047 {
+    if (-2048..=2047).contains(&offset) {
       writeln!(self.f, "  lw {dest}, {offset}({addr})")
     } else {
       self.addi(self.temp, addr, offset)?;
       writeln!(self.f, "  lw {dest}, 0({})", self.temp)
     }
   }
+
+  /// Generates `bnez`.
+  pub fn bnez(&mut self, cond: &str, label: &str) -> Result<()> {
+    writeln!(self.f, "  bnez {cond}, {label}")
+  }
+
+  /// Generates `beqz`.
+  pub fn beqz(&mut self, cond: &str, label: &str) -> Result<()> {
+    writeln!(self.f, "  beqz {cond}, {label}")
+  }
+
+  /// Generates `j`.
+  pub fn j(&mut self, label: &str) -> Result<()> {
+    writeln!(self.f, "  j {label}")
+  }
+
+  /// Generates `call`.
+  pub fn call(&mut self, func: &str) -> Result<()> {
+    writeln!(self.f, "  call {func}")
+  }
+
+  /// Generates function prologue.
   pub fn prologue(&mut self, func_name: &str, info: &FunctionInfo) -> Result<()> {
     writeln!(self.f, "  .text")?;
     writeln!(self.f, "  .globl {}", &func_name[1..])?;
@@ -49,6 +119,8 @@ impl<'f> AsmBuilder<'f> {
     }
     Ok(())
   }
+
+  /// Generates function epilogue.
   pub fn epilogue(&mut self, info: &FunctionInfo) -> Result<()> {
     let offset = info.sp_offset() as i32;
     if offset != 0 {
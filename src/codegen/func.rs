@@ -1,50 +1,144 @@
-use koopa::ir::{BasicBlock, Type, Value};
+use koopa::ir::entities::ValueData;
+use koopa::ir::{BasicBlock, Function, TypeKind, ValueKind};
 use std::collections::HashMap;
+
 /// Function information.
 pub struct FunctionInfo {
-  is_leaf: bool,
-  max_arg_num: usize,
+  func: Function,
+  /// Maximum argument number of call instructions in the function.
+  /// `None` if the current function is a leaf function.
+  max_arg_num: Option<usize>,
   alloc_size: usize,
-  allocs: HashMap<Value, usize>,
+  allocs: HashMap<*const ValueData, Slot>,
   bbs: HashMap<BasicBlock, String>,
+  /// Offset of the area where basic block arguments are staged, if any.
+  copies: Option<usize>,
+  /// Number of labels created besides those of basic blocks.
+  labels: usize,
 }
+
 impl FunctionInfo {
   /// Creates a new function information.
-  pub fn new(is_leaf: bool, max_arg_num: usize) -> Self {
+  pub fn new(func: Function) -> Self {
     Self {
-      is_leaf,
-      max_arg_num,
+      func,
+      max_arg_num: None,
       alloc_size: 0,
       allocs: HashMap::new(),
       bbs: HashMap::new(),
+      copies: None,
+      labels: 0,
     }
   }
-  /// Creates a new stack slot allocation.
-  pub fn new_alloc(&mut self, alloc: Value, ty: &Type) {
-    self.allocs.insert(alloc, self.alloc_size);
-    self.alloc_size += ty.size();
+
+  /// Returns the current function.
+  pub fn func(&self) -> Function {
+    self.func
   }
-  /// Returns the size of the given allocation.
-  pub fn size_of(&self, alloc: Value) -> Option<usize> {
-    self.allocs.get(&alloc).copied()
+
+  /// Returns `true` if the current function is a leaf function.
+  pub fn is_leaf(&self) -> bool {
+    self.max_arg_num.is_none()
   }
+
+  /// Allocates a new stack slot for the given value data.
+  pub fn alloc_slot(&mut self, value: &ValueData) {
+    match value.kind() {
+      ValueKind::Alloc(_) => {
+        self.allocs.insert(value, Slot::new(self.alloc_size, false));
+        self.alloc_size += match value.ty().kind() {
+          TypeKind::Pointer(base) => base.size(),
+          _ => unreachable!(),
+        };
+      }
+      _ => {
+        let is_ptr = matches!(value.ty().kind(), TypeKind::Pointer(_));
+        self.allocs.insert(value, Slot::new(self.alloc_size, is_ptr));
+        self.alloc_size += value.ty().size();
+      }
+    }
+  }
+
+  /// Allocates the area for staging basic block arguments, which holds `len` words.
+  pub fn alloc_copies(&mut self, len: usize) {
+    self.copies = Some(self.alloc_size);
+    self.alloc_size += len * 4;
+  }
+
+  /// Returns the slot of the `index`-th word in the staging area.
+  pub fn copy_slot(&self, index: usize) -> Slot {
+    Slot::new(self.copies.unwrap() + index * 4 + self.outgoing_args_size(), false)
+  }
+
+  /// Returns the slot of the `index`-th argument of the function, which is passed
+  /// through the stack of the caller.
+  pub fn arg_slot(&self, index: usize, is_ptr: bool) -> Slot {
+    Slot::new(self.sp_offset() + (index - 8) * 4, is_ptr)
+  }
+
+  /// Logs argument number.
+  pub fn log_arg_num(&mut self, arg_num: usize) {
+    if self.max_arg_num.is_none() || arg_num > self.max_arg_num.unwrap() {
+      self.max_arg_num = Some(arg_num);
+    }
+  }
+
+  /// Logs basic block name.
+  pub fn log_bb_name(&mut self, bb: BasicBlock, func_name: &str, name: &Option<String>) {
+    let id = self.bbs.len();
+    let label = match name.as_ref() {
+      Some(name) => format!(".L{}_{}_{}", &func_name[1..], &name[1..], id),
+      None => format!(".L{}_{}", &func_name[1..], id),
+    };
+    self.bbs.insert(bb, label);
+  }
+
+  /// Creates a new label on the way to the given basic block.
+  pub fn new_label(&mut self, bb: BasicBlock) -> String {
+    self.labels += 1;
+    format!("{}_args{}", self.bb_name(bb), self.labels)
+  }
+
   /// Returns a reference to the name of the given basic block.
   pub fn bb_name(&self, bb: BasicBlock) -> &str {
     self.bbs.get(&bb).as_ref().unwrap()
   }
+
+  /// Returns the slot offset (relative to `sp`) of the given value data.
+  pub fn slot_offset(&self, value: &ValueData) -> Option<Slot> {
+    self
+      .allocs
+      .get(&(value as *const ValueData))
+      .map(|&slot| Slot::new(slot.offset + self.outgoing_args_size(), slot.is_ptr))
+  }
+
   /// Returns the stack pointer offset.
   pub fn sp_offset(&self) -> usize {
     // slot for storing return address
-    let ra = if self.is_leaf { 0 } else { 1 };
-    // slot for storing arguments and temporary arguments
-    let (args, temp_args) = if self.max_arg_num > 8 {
-      (self.max_arg_num - 8, 8)
-    } else {
-      (0, self.max_arg_num)
-    };
+    let ra = if self.is_leaf() { 0 } else { 4 };
     // the final offset
-    let offset = ra + args + temp_args + self.alloc_size;
+    let offset = ra + self.alloc_size + self.outgoing_args_size();
     // align to 16 bytes
-    (offset + 15) / 16 * 16
+    offset.div_ceil(16) * 16
+  }
+
+  /// Returns the size of the area for passing arguments through the stack.
+  fn outgoing_args_size(&self) -> usize {
+    self.max_arg_num.map_or(0, |n| n.saturating_sub(8) * 4)
+  }
+}
+
+/// A stack slot.
+#[derive(Clone, Copy)]
+pub struct Slot {
+  pub offset: usize,
+  /// `true` if the slot stores a pointer.
+  pub is_ptr: bool,
+}
+
+impl Slot {
+  /// Creates a new stack slot.
+  fn new(offset: usize, is_ptr: bool) -> Self {
+    Self { offset, is_ptr }
   }
 }
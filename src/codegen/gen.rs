@@ -67,26 +67,48 @@ impl<'p, 'i> GenerateToAsm<'p, 'i> for FunctionData {
     if self.layout().entry_bb().is_none() {
       return Ok(());
     }
-    // allocation stack slots and log argument number
+    // allocate stack slots and log argument number
     let func = cur_func_mut!(info);
-    for value in self.dfg().values().values() {
-      // allocate stack slot
-      if value.kind().is_local_inst() && !value.used_by().is_empty() {
+    // arguments passed through registers are saved, as calls overwrite them
+    for &param in self.params().iter().take(8) {
+      let value = self.dfg().value(param);
+      if !value.used_by().is_empty() {
         func.alloc_slot(value);
       }
-      // log argument number
-      if let ValueKind::Call(call) = value.kind() {
-        func.log_arg_num(call.args().len());
+    }
+    let mut max_bb_params = 0;
+    for (&bb, node) in self.layout().bbs() {
+      let data = self.dfg().bb(bb);
+      func.log_bb_name(bb, self.name(), data.name());
+      for &param in data.params() {
+        let value = self.dfg().value(param);
+        if !value.used_by().is_empty() {
+          func.alloc_slot(value);
+        }
+      }
+      max_bb_params = max_bb_params.max(data.params().len());
+      for &inst in node.insts().keys() {
+        let value = self.dfg().value(inst);
+        // allocate stack slot
+        if matches!(value.kind(), ValueKind::Alloc(_)) || !value.used_by().is_empty() {
+          func.alloc_slot(value);
+        }
+        // log argument number
+        if let ValueKind::Call(call) = value.kind() {
+          func.log_arg_num(call.args().len());
+        }
       }
     }
-    // generate basic block names
-    for (&bb, data) in self.dfg().bbs() {
-      // basic block parameters are not supported
-      assert!(data.params().is_empty());
-      func.log_bb_name(bb, data.name());
+    if max_bb_params > 0 {
+      func.alloc_copies(max_bb_params);
     }
     // generate prologue
     AsmBuilder::new(f, "t0").prologue(self.name(), func)?;
+    for (i, &param) in self.params().iter().take(8).enumerate() {
+      if let Some(slot) = func.slot_offset(self.dfg().value(param)) {
+        AsmBuilder::new(f, "t0").sw(&format!("a{i}"), "sp", slot.offset as i32)?;
+      }
+    }
     // generate instructions in basic blocks
     for (bb, node) in self.layout().bbs() {
       let name = bb.generate(f, info)?;
@@ -118,7 +140,12 @@ impl<'p, 'i> GenerateToAsm<'p, 'i> for Value {
       let value = info.program().func(func.func()).dfg().value(*self);
       Ok(match value.kind() {
         ValueKind::Integer(i) => AsmValue::Const(i.value()),
-        ValueKind::FuncArgRef(i) => AsmValue::Arg(i.index()),
+        // any value will do
+        ValueKind::Undef(_) => AsmValue::Const(0),
+        ValueKind::FuncArgRef(i) if i.index() >= 8 => {
+          let is_ptr = matches!(value.ty().kind(), TypeKind::Pointer(_));
+          AsmValue::Local(func.arg_slot(i.index(), is_ptr))
+        }
         _ => AsmValue::from(func.slot_offset(value)),
       })
     }
@@ -200,13 +227,7 @@ impl<'p, 'i> GenerateToAsm<'p, 'i> for Store {
   type Out = ();
 
   fn generate(&self, f: &mut File, info: &mut ProgramInfo) -> Result<Self::Out> {
-    let sp_offset = cur_func!(info).sp_offset();
-    let value = self.value().generate(f, info)?;
-    if matches!(value, AsmValue::Arg(_)) {
-      value.write_arg_to(f, "t0", sp_offset)?;
-    } else {
-      value.write_to(f, "t0")?;
-    }
+    self.value().generate(f, info)?.write_to(f, "t0")?;
     let dest = self.dest().generate(f, info)?;
     if dest.is_ptr() {
       dest.write_to(f, "t1")?;
@@ -308,10 +329,26 @@ impl<'p, 'i> GenerateToAsm<'p, 'i> for Branch {
 
   fn generate(&self, f: &mut File, info: &mut ProgramInfo) -> Result<Self::Out> {
     self.cond().generate(f, info)?.write_to(f, "t0")?;
-    let tlabel = self.true_bb().generate(f, info)?;
-    AsmBuilder::new(f, "t1").bnez("t0", tlabel)?;
-    let flabel = self.false_bb().generate(f, info)?;
-    AsmBuilder::new(f, "t1").j(flabel)
+    let tlabel = self.true_bb().generate(f, info)?.to_string();
+    let flabel = self.false_bb().generate(f, info)?.to_string();
+    // arguments are passed after branching to the side taking them
+    if self.true_args().is_empty() {
+      AsmBuilder::new(f, "t1").bnez("t0", &tlabel)?;
+      pass_args(f, info, self.false_bb(), self.false_args())?;
+      AsmBuilder::new(f, "t1").j(&flabel)
+    } else if self.false_args().is_empty() {
+      AsmBuilder::new(f, "t1").beqz("t0", &flabel)?;
+      pass_args(f, info, self.true_bb(), self.true_args())?;
+      AsmBuilder::new(f, "t1").j(&tlabel)
+    } else {
+      let label = cur_func_mut!(info).new_label(self.false_bb());
+      AsmBuilder::new(f, "t1").beqz("t0", &label)?;
+      pass_args(f, info, self.true_bb(), self.true_args())?;
+      AsmBuilder::new(f, "t1").j(&tlabel)?;
+      writeln!(f, "{label}:")?;
+      pass_args(f, info, self.false_bb(), self.false_args())?;
+      AsmBuilder::new(f, "t1").j(&flabel)
+    }
   }
 }
 
@@ -319,11 +356,45 @@ impl<'p, 'i> GenerateToAsm<'p, 'i> for Jump {
   type Out = ();
 
   fn generate(&self, f: &mut File, info: &mut ProgramInfo) -> Result<Self::Out> {
+    pass_args(f, info, self.target(), self.args())?;
     let label = self.target().generate(f, info)?;
     AsmBuilder::new(f, "t0").j(label)
   }
 }
 
+/// Stores the arguments to the parameters of the basic block.
+fn pass_args(f: &mut File, info: &mut ProgramInfo, bb: BasicBlock, args: &[Value]) -> Result<()> {
+  let dfg = info.program().func(cur_func!(info).func()).dfg();
+  let params = dfg.bb(bb).params();
+  // parameters passed to other parameters must be read before they are overwritten,
+  // so the arguments are staged first
+  let staged = args
+    .iter()
+    .enumerate()
+    .any(|(i, arg)| params.iter().position(|param| param == arg).is_some_and(|j| j != i));
+  if staged {
+    for (i, arg) in args.iter().enumerate() {
+      arg.generate(f, info)?.write_to(f, "t0")?;
+      let slot = cur_func!(info).copy_slot(i);
+      AsmValue::Local(slot).read_from(f, "t0", "t1")?;
+    }
+  }
+  for (i, (arg, &param)) in args.iter().zip(params).enumerate() {
+    let param = dfg.value(param);
+    // unused parameters have no slots
+    if cur_func!(info).slot_offset(param).is_none() {
+      continue;
+    }
+    if staged {
+      AsmValue::Local(cur_func!(info).copy_slot(i)).write_to(f, "t0")?;
+    } else {
+      arg.generate(f, info)?.write_to(f, "t0")?;
+    }
+    asm_value!(info, param).read_from(f, "t0", "t1")?;
+  }
+  Ok(())
+}
+
 impl<'p, 'i> GenerateValueToAsm<'p, 'i> for Call {
   type Out = ();
 
@@ -50,21 +50,6 @@ impl<'i> AsmValue<'i> {
     }
   }
 
-  /// Writes the assembly value (argument) to the given register.
-  pub fn write_arg_to(&self, f: &mut File, reg: &'static str, sp_offset: usize) -> Result<()> {
-    let mut builder = AsmBuilder::new(f, reg);
-    match self {
-      Self::Arg(index) => {
-        if *index < 8 {
-          builder.mv(reg, &format!("a{}", *index))
-        } else {
-          builder.lw(reg, "sp", (sp_offset + (*index - 8) * 4) as i32)
-        }
-      }
-      _ => unreachable!(),
-    }
-  }
-
   /// Reads the value of the given register to the assembly value.
   pub fn read_from(&self, f: &mut File, reg: &'static str, temp: &'static str) -> Result<()> {
     let mut builder = AsmBuilder::new(f, temp);
@@ -1,30 +1,35 @@
-use crate::ir::{Error, Result};
+use crate::ir::Locations;
 use crate::ir::function::FunctionInfo;
+use crate::sema::{Analysis, SymbolId};
+use crate::source::Span;
 use std::vec::Vec;
 use std::collections::HashMap;
-use koopa::ir::{Value, Function};
+use koopa::ir::{BasicBlock, Value, Function};
 
 pub struct Context<'ast> {
-    value_blocks: Vec<HashMap<&'ast str, Value>>,
-    functions: HashMap<&'ast str, Function>,
+    pub analysis: &'ast Analysis,
+    /// The allocations of variables and constant arrays.
+    values: HashMap<SymbolId, Value>,
+    functions: HashMap<SymbolId, Function>,
+    loops: Vec<(BasicBlock, BasicBlock)>,
+    pub locations: Locations,
     pub active_fcuntion: Option<FunctionInfo>,
 }
 
 impl<'ast> Context<'ast> {
-    pub fn new() -> Self {
+    pub fn new(analysis: &'ast Analysis) -> Self {
         Self {
-            value_blocks: vec![HashMap::new()],
+            analysis,
+            values: HashMap::new(),
             functions: HashMap::new(),
+            loops: Vec::new(),
+            locations: Locations::new(),
             active_fcuntion: None,
         }
     }
 
-    pub fn active_block(&self) -> &HashMap<&'ast str, Value> {
-        self.value_blocks.last().unwrap()
-    }
-
-    pub fn active_block_mut(&mut self) -> &mut HashMap<&'ast str, Value> {
-        self.value_blocks.last_mut().unwrap()
+    pub fn is_global(&self) -> bool {
+        self.active_fcuntion.is_none()
     }
 
     pub fn active_function(&self) -> &FunctionInfo {
@@ -35,45 +40,37 @@ impl<'ast> Context<'ast> {
         self.active_fcuntion.as_mut().unwrap()
     }
 
-    pub fn insert_value(&mut self, id: &'ast str, value: Value) -> Result<()> {
-        let cur = self.active_block_mut();
-        if cur.contains_key(id) {
-            Err(Error::DuplicateDefinition)
-        } else {
-            cur.insert(id, value);
-            Ok(())
-        }
+    pub fn insert_value(&mut self, symbol: SymbolId, value: Value) {
+        self.values.insert(symbol, value);
     }
 
-    pub fn get_value(&self, id: &str) -> Result<Value> {
-        let mut block_i: i32 = (self.value_blocks.len() as i32) - 1;
-        while block_i >= 0 {
-            match self.value_blocks.get(block_i as usize).unwrap().get(id) {
-                Some(value) => return Ok(value.clone()),
-                None => block_i -= 1,
-            }
-        }
-        Err(Error::SymbolNotFound)
+    pub fn value(&self, symbol: SymbolId) -> Value {
+        self.values[&symbol]
     }
 
-    pub fn insert_function(&mut self, id: &'ast str, func: Function) -> Result<()> {
-        if self.functions.contains_key(id) {
-            Err(Error::DuplicateDefinition)
-        } else {
-            self.functions.insert(id, func);
-            Ok(())
-        }
+    pub fn insert_function(&mut self, symbol: SymbolId, func: Function) {
+        self.functions.insert(symbol, func);
+    }
+
+    pub fn function(&self, symbol: SymbolId) -> Function {
+        self.functions[&symbol]
+    }
+
+    /// Returns the entry and exit block of the innermost loop.
+    pub fn active_loop(&self) -> Option<(BasicBlock, BasicBlock)> {
+        self.loops.last().copied()
     }
 
-    pub fn get_function(&self, id: &str) -> Result<Function> {
-        self.functions.get(id).cloned().ok_or(Error::SymbolNotFound)
+    pub fn push_loop(&mut self, entry: BasicBlock, exit: BasicBlock) {
+        self.loops.push((entry, exit));
     }
 
-    pub fn push(&mut self) {
-        self.value_blocks.push(HashMap::new());
+    pub fn pop_loop(&mut self) {
+        self.loops.pop();
     }
 
-    pub fn pop(&mut self) {
-        self.value_blocks.pop();
+    /// Records where in the source the instruction comes from.
+    pub fn locate(&mut self, inst: Value, span: Span) {
+        self.locations.insert(inst, span);
     }
 }
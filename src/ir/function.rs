@@ -7,11 +7,11 @@ pub struct FunctionInfo {
     entry: BasicBlock,
     end: BasicBlock,
     active: BasicBlock,
-    return_value: Value,
+    return_value: Option<Value>,
 }
 
 impl FunctionInfo {
-    pub fn new(function: Function, entry: BasicBlock, end: BasicBlock, return_value: Value) -> Self {
+    pub fn new(function: Function, entry: BasicBlock, end: BasicBlock, return_value: Option<Value>) -> Self {
         Self {
             function,
             entry,
@@ -21,18 +21,6 @@ impl FunctionInfo {
         }
     }
 
-    pub fn function(&self) -> Function {
-        self.function
-    }
-
-    pub fn end(&self) -> BasicBlock {
-        self.end
-    }
-
-    pub fn return_value(&self) -> Value {
-        self.return_value
-    }
-
     pub fn create_basic_block(&self, program: &mut Program, name: Option<&str>) -> BasicBlock {
         program
         .func_mut(self.function)
@@ -41,6 +29,14 @@ impl FunctionInfo {
         .basic_block(name.map(|s| s.into()))
     }
 
+    pub fn value_type(&self, program: &Program, value: Value) -> Type {
+        if value.is_global() {
+            program.borrow_value(value).ty().clone()
+        } else {
+            program.func(self.function).dfg().value(value).ty().clone()
+        }
+    }
+
     pub fn create_value<'p>(&self, program: &'p mut Program) -> LocalBuilder<'p> {
         program.func_mut(self.function).dfg_mut().new_value()
     }
@@ -81,9 +77,42 @@ impl FunctionInfo {
         alloc
     }
 
-    pub fn finish_allocate(&self, program: &mut Program) -> Value {
-        let jump = self.create_value(program).jump(self.active);
+    /// Stores the return value and leaves the function through the exit block.
+    pub fn generate_return(&mut self, program: &mut Program, value: Option<Value>) {
+        if let (Some(value), Some(ret_val)) = (value, self.return_value) {
+            let store = self.create_value(program).store(value, ret_val);
+            self.push_instruction(program, store);
+        }
+        let jump = self.create_value(program).jump(self.end);
+        self.push_instruction(program, jump);
+
+        // code after `return` is unreachable, but still needs a block to live in
+        let next = self.create_basic_block(program, "%after_return".into());
+        self.push_basic_block(program, next);
+    }
+
+    /// Finishes the function once its body is generated: the entry block jumps to
+    /// `body` after the allocations, falling off the end returns 0 or nothing, and
+    /// the exit block returns the stored value.
+    pub fn finish(&mut self, program: &mut Program, body: BasicBlock) {
+        let jump = self.create_value(program).jump(body);
         self.insert_instruction(program, self.entry, jump);
-        jump
+
+        if let Some(ret_val) = self.return_value {
+            let zero = self.create_value(program).integer(0);
+            let store = self.create_value(program).store(zero, ret_val);
+            self.push_instruction(program, store);
+        }
+        let jump = self.create_value(program).jump(self.end);
+        self.push_instruction(program, jump);
+
+        self.push_basic_block(program, self.end);
+        let ret = self.return_value.map(|ret_val| {
+            let load = self.create_value(program).load(ret_val);
+            self.push_instruction(program, load);
+            load
+        });
+        let ret = self.create_value(program).ret(ret);
+        self.push_instruction(program, ret);
     }
 }
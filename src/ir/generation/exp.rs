@@ -1,15 +1,16 @@
 use crate::ir::GenerateIR;
-use crate::ir::Result;
 use crate::ir::context::Context;
 use crate::ast::exp::*;
-use koopa::ir::{Program, Value};
+use crate::ast::func::FuncRParams;
+use crate::source::Span;
+use koopa::ir::{BasicBlock, Program, Type, Value};
 use koopa::ir::builder_traits::*;
 use koopa::ir::values::BinaryOp;
 
 impl<'ast> GenerateIR<'ast> for Exp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         self.exp.generate(program, context)
     }
 }
@@ -17,16 +18,14 @@ impl<'ast> GenerateIR<'ast> for Exp {
 impl<'ast> GenerateIR<'ast> for PrimaryExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Exp(exp) => exp.generate(program, context),
             Self::LVal(lval) => lval.generate(program, context),
-            Self::Number(number) => Ok(
-                context
+            Self::Number(number) => context
                 .active_function()
                 .create_value(program)
-                .integer(*number)
-            ),
+                .integer(number.value as i32),
         }
     }
 }
@@ -34,22 +33,12 @@ impl<'ast> GenerateIR<'ast> for PrimaryExp {
 impl<'ast> GenerateIR<'ast> for UnaryExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Primary(exp) => exp.generate(program, context),
-            Self::Func(id, params) => {
-                let args = match params {
-                    None => Vec::new(),
-                    Some(params) => params.generate(program, context)?,
-                };
-                let func = context.get_function(id)?;
-                let active_func = context.active_function();
-                let call = active_func.create_value(program).call(func, args);
-                active_func.push_instruction(program, call);
-                Ok(call)
-            },
+            Self::Func(_, params, span) => generate_call(params, *span, program, context),
             Self::Unary(op, exp) => {
-                let exp = exp.generate(program, context)?;
+                let exp = exp.generate(program, context);
                 let active_func = context.active_function();
                 let zero = active_func.create_value(program).integer(0);
                 let value = match op {
@@ -58,21 +47,39 @@ impl<'ast> GenerateIR<'ast> for UnaryExp {
                     UnaryOp::Not => active_func.create_value(program).binary(BinaryOp::Eq, zero, exp),
                 };
                 active_func.push_instruction(program, value);
-                Ok(value)
+                value
             }
         }
     }
 }
 
+/// Generates a function call, whose value may be of the unit type.
+fn generate_call<'ast>(
+    params: &'ast Option<FuncRParams>,
+    span: Span,
+    program: &mut Program,
+    context: &mut Context<'ast>,
+) -> Value {
+    let func = context.function(context.analysis.resolve(span));
+    let args = match params {
+        None => Vec::new(),
+        Some(params) => params.generate(program, context),
+    };
+    let active_func = context.active_function();
+    let call = active_func.create_value(program).call(func, args);
+    active_func.push_instruction(program, call);
+    call
+}
+
 impl<'ast> GenerateIR<'ast> for MulExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Unary(exp) => exp.generate(program, context),
-            Self::MulUnary(lhs, op, rhs) => {
-                let lhs = lhs.generate(program, context)?;
-                let rhs = rhs.generate(program, context)?;
+            Self::MulUnary(lhs, op, rhs, span) => {
+                let lhs = lhs.generate(program, context);
+                let rhs = rhs.generate(program, context);
                 let active_func = context.active_function();
                 let value = match op {
                     MulOp::Mul => active_func.create_value(program).binary(BinaryOp::Mul, lhs, rhs),
@@ -80,7 +87,10 @@ impl<'ast> GenerateIR<'ast> for MulExp {
                     MulOp::Mod => active_func.create_value(program).binary(BinaryOp::Mod, lhs, rhs),
                 };
                 active_func.push_instruction(program, value);
-                Ok(value)
+                if matches!(op, MulOp::Div | MulOp::Mod) {
+                    context.locate(value, *span);
+                }
+                value
             }
         }
     }
@@ -89,19 +99,19 @@ impl<'ast> GenerateIR<'ast> for MulExp {
 impl<'ast> GenerateIR<'ast> for AddExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Mul(exp) => exp.generate(program, context),
             Self::AddMul(lhs, op, rhs) => {
-                let lhs = lhs.generate(program, context)?;
-                let rhs = rhs.generate(program, context)?;
+                let lhs = lhs.generate(program, context);
+                let rhs = rhs.generate(program, context);
                 let active_func = context.active_function();
                 let value = match op {
                     AddOp::Add => active_func.create_value(program).binary(BinaryOp::Add, lhs, rhs),
                     AddOp::Sub => active_func.create_value(program).binary(BinaryOp::Sub, lhs, rhs),
                 };
                 active_func.push_instruction(program, value);
-                Ok(value)
+                value
             }
         }
     }
@@ -110,12 +120,12 @@ impl<'ast> GenerateIR<'ast> for AddExp {
 impl<'ast> GenerateIR<'ast> for RelExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Add(exp) => exp.generate(program, context),
             Self::RelAdd(lhs, op, rhs) => {
-                let lhs = lhs.generate(program, context)?;
-                let rhs = rhs.generate(program, context)?;
+                let lhs = lhs.generate(program, context);
+                let rhs = rhs.generate(program, context);
                 let active_func = context.active_function();
                 let value = match op {
                     RelOp::Lt => active_func.create_value(program).binary(BinaryOp::Lt, lhs, rhs),
@@ -124,7 +134,7 @@ impl<'ast> GenerateIR<'ast> for RelExp {
                     RelOp::Ge => active_func.create_value(program).binary(BinaryOp::Ge, lhs, rhs),
                 };
                 active_func.push_instruction(program, value);
-                Ok(value)
+                value
             }
         }
     }
@@ -133,19 +143,19 @@ impl<'ast> GenerateIR<'ast> for RelExp {
 impl<'ast> GenerateIR<'ast> for EqExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Rel(exp) => exp.generate(program, context),
             Self::EqRel(lhs, op, rhs) => {
-                let lhs = lhs.generate(program, context)?;
-                let rhs = rhs.generate(program, context)?;
+                let lhs = lhs.generate(program, context);
+                let rhs = rhs.generate(program, context);
                 let active_func = context.active_function();
                 let value = match op {
                     EqOp::Eq => active_func.create_value(program).binary(BinaryOp::Eq, lhs, rhs),
                     EqOp::Ne => active_func.create_value(program).binary(BinaryOp::NotEq, lhs, rhs),
                 };
                 active_func.push_instruction(program, value);
-                Ok(value)
+                value
             }
         }
     }
@@ -154,17 +164,10 @@ impl<'ast> GenerateIR<'ast> for EqExp {
 impl<'ast> GenerateIR<'ast> for LAndExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::Eq(exp) => exp.generate(program, context),
-            Self::LAndEq(lhs, rhs) => {
-                let lhs = lhs.generate(program, context)?;
-                let rhs = rhs.generate(program, context)?;
-                let active_func = context.active_function();
-                let value = active_func.create_value(program).binary(BinaryOp::And, lhs, rhs);
-                active_func.push_instruction(program, value);
-                Ok(value)
-            }
+            Self::LAndEq(..) => generate_logic(self, program, context),
         }
     }
 }
@@ -172,17 +175,111 @@ impl<'ast> GenerateIR<'ast> for LAndExp {
 impl<'ast> GenerateIR<'ast> for LOrExp {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Self::LAnd(exp) => exp.generate(program, context),
-            Self::LOrLAnd(lhs, rhs) => {
-                let lhs = lhs.generate(program, context)?;
-                let rhs = rhs.generate(program, context)?;
+            Self::LOrLAnd(..) => generate_logic(self, program, context),
+        }
+    }
+}
+
+/// Expressions that can be lowered to control flow directly.
+pub trait GenerateCond<'ast> {
+    /// Generates a branch to `true_bb` if the expression is non-zero, or to `false_bb` otherwise.
+    fn generate_cond(
+        &'ast self,
+        program: &mut Program,
+        context: &mut Context<'ast>,
+        true_bb: BasicBlock,
+        false_bb: BasicBlock,
+    );
+}
+
+impl<'ast> GenerateCond<'ast> for Exp {
+    fn generate_cond(
+        &'ast self,
+        program: &mut Program,
+        context: &mut Context<'ast>,
+        true_bb: BasicBlock,
+        false_bb: BasicBlock,
+    ) {
+        self.exp.generate_cond(program, context, true_bb, false_bb)
+    }
+}
+
+impl<'ast> GenerateCond<'ast> for LAndExp {
+    fn generate_cond(
+        &'ast self,
+        program: &mut Program,
+        context: &mut Context<'ast>,
+        true_bb: BasicBlock,
+        false_bb: BasicBlock,
+    ) {
+        match self {
+            Self::Eq(exp) => {
+                let cond = exp.generate(program, context);
                 let active_func = context.active_function();
-                let value = active_func.create_value(program).binary(BinaryOp::Or, lhs, rhs);
-                active_func.push_instruction(program, value);
-                Ok(value)
+                let br = active_func.create_value(program).branch(cond, true_bb, false_bb);
+                active_func.push_instruction(program, br);
+            }
+            Self::LAndEq(lhs, rhs) => {
+                let rhs_bb = context.active_function().create_basic_block(program, "%land_rhs".into());
+                lhs.generate_cond(program, context, rhs_bb, false_bb);
+                context.active_function_mut().push_basic_block(program, rhs_bb);
+                let cond = rhs.generate(program, context);
+                let active_func = context.active_function();
+                let br = active_func.create_value(program).branch(cond, true_bb, false_bb);
+                active_func.push_instruction(program, br);
             }
         }
     }
 }
+
+impl<'ast> GenerateCond<'ast> for LOrExp {
+    fn generate_cond(
+        &'ast self,
+        program: &mut Program,
+        context: &mut Context<'ast>,
+        true_bb: BasicBlock,
+        false_bb: BasicBlock,
+    ) {
+        match self {
+            Self::LAnd(exp) => exp.generate_cond(program, context, true_bb, false_bb),
+            Self::LOrLAnd(lhs, rhs) => {
+                let rhs_bb = context.active_function().create_basic_block(program, "%lor_rhs".into());
+                lhs.generate_cond(program, context, true_bb, rhs_bb);
+                context.active_function_mut().push_basic_block(program, rhs_bb);
+                rhs.generate_cond(program, context, true_bb, false_bb)
+            }
+        }
+    }
+}
+
+/// Generates the 0/1 value of a short-circuit logical expression.
+fn generate_logic<'ast, C: GenerateCond<'ast>>(
+    cond: &'ast C,
+    program: &mut Program,
+    context: &mut Context<'ast>,
+) -> Value {
+    let active_func = context.active_function();
+    let result = active_func.allocate(program, Type::get_i32(), None);
+    let true_bb = active_func.create_basic_block(program, "%logic_true".into());
+    let false_bb = active_func.create_basic_block(program, "%logic_false".into());
+    let end_bb = active_func.create_basic_block(program, "%logic_end".into());
+    cond.generate_cond(program, context, true_bb, false_bb);
+
+    let active_func = context.active_function_mut();
+    for (bb, value) in [(true_bb, 1), (false_bb, 0)] {
+        active_func.push_basic_block(program, bb);
+        let value = active_func.create_value(program).integer(value);
+        let store = active_func.create_value(program).store(value, result);
+        active_func.push_instruction(program, store);
+        let jump = active_func.create_value(program).jump(end_bb);
+        active_func.push_instruction(program, jump);
+    }
+
+    active_func.push_basic_block(program, end_bb);
+    let load = active_func.create_value(program).load(result);
+    active_func.push_instruction(program, load);
+    load
+}
@@ -3,23 +3,49 @@ mod var;
 mod stmt;
 mod func;
 
-use crate::ir::Result;
 use crate::ir::context::Context;
 use crate::ast::*;
-use koopa::ir::Program;
+use crate::sema;
+use koopa::ir::{Program, Type};
 
 pub trait GenerateIR<'ast> {
     type Out;
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out>;
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out;
 }
 
 impl<'ast> GenerateIR<'ast> for CompUnit {
     type Out = ();
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        for func_def in &self.func_defs {
-            func_def.generate(program, context)?;
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        for item in &self.items {
+            if let GlobalItem::FuncDef(func_def) = item {
+                func_def.declare(program, context);
+            }
         }
-        Ok(())
+        for item in &self.items {
+            item.generate(program, context);
+        }
+    }
+}
+
+impl<'ast> GenerateIR<'ast> for GlobalItem {
+    type Out = ();
+
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        match self {
+            Self::ConstDecl(decl) => decl.generate(program, context),
+            Self::Decl(decl) => decl.generate(program, context),
+            Self::FuncDef(func_def) => func_def.generate(program, context),
+        }
+    }
+}
+
+/// Returns the Koopa type of values of the SysY type.
+pub fn koopa_type(ty: &sema::Type) -> Type {
+    match ty {
+        sema::Type::Void => Type::get_unit(),
+        sema::Type::Int => Type::get_i32(),
+        sema::Type::Array(dims) => var::array_type(Type::get_i32(), dims),
+        sema::Type::Pointer(dims) => Type::get_pointer(var::array_type(Type::get_i32(), dims)),
     }
 }
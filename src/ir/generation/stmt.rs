@@ -1,6 +1,6 @@
 use crate::ir::GenerateIR;
-use crate::ir::{Result, Error};
 use crate::ir::context::Context;
+use crate::ir::generation::exp::GenerateCond;
 use crate::ast::stmt::*;
 use koopa::ir::builder::LocalInstBuilder;
 use koopa::ir::Program;
@@ -8,22 +8,20 @@ use koopa::ir::Program;
 impl<'ast> GenerateIR<'ast> for Block {
     type Out = ();
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        context.push();
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         for item in &self.items {
-            item.generate(program, context)?;
+            item.generate(program, context);
         }
-        context.pop();
-        Ok(())
     }
 }
 
 impl<'ast> GenerateIR<'ast> for BlockItem {
     type Out = ();
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             BlockItem::Stmt(stmt) => stmt.generate(program, context),
+            BlockItem::ConstDecl(decl) => decl.generate(program, context),
             BlockItem::Decl(decl) => decl.generate(program, context),
         }
     }
@@ -32,12 +30,15 @@ impl<'ast> GenerateIR<'ast> for BlockItem {
 impl<'ast> GenerateIR<'ast> for Stmt {
     type Out = ();
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         match self {
             Stmt::Assign(assign) => assign.generate(program, context),
+            Stmt::Exp(exp) => exp.generate(program, context),
             Stmt::Sblock(sblock) => sblock.generate(program, context),
             Stmt::If(sif) => sif.generate(program, context),
             Stmt::While(swhile) => swhile.generate(program, context),
+            Stmt::Break(sbreak) => sbreak.generate(program, context),
+            Stmt::Continue(scontinue) => scontinue.generate(program, context),
             Stmt::Return(ret) => ret.generate(program, context),
         }
     }
@@ -45,104 +46,117 @@ impl<'ast> GenerateIR<'ast> for Stmt {
 
 impl<'ast> GenerateIR<'ast> for Assign {
     type Out = ();
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        let exp = self.exp.generate(program, context)?;
-        let lval = context.get_value(&self.lval.id)?;
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let lval = self.lval.generate_ptr(program, context);
+        let exp = self.exp.generate(program, context);
         let active_func = context.active_function();
         let store = active_func.create_value(program).store(exp, lval);
         active_func.push_instruction(program, store);
-        Ok(())
+    }
+}
+
+impl<'ast> GenerateIR<'ast> for ExpStmt {
+    type Out = ();
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        if let Some(exp) = &self.exp {
+            exp.generate(program, context);
+        }
     }
 }
 
 impl<'ast> GenerateIR<'ast> for Sblock {
     type Out = ();
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         self.block.generate(program, context)
     }
 }
 
 impl<'ast> GenerateIR<'ast> for If {
     type Out = ();
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        let cond = self.cond.generate(program, context)?;
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         let active_func = context.active_function_mut();
-
         let then_bb = active_func.create_basic_block(program, "%then".into());
         let else_bb = active_func.create_basic_block(program, "%else".into());
         let next_bb = active_func.create_basic_block(program, "%body".into());
-        let br = active_func.create_value(program).branch(cond, then_bb, else_bb);
-        active_func.push_instruction(program, br);
+        self.cond.generate_cond(program, context, then_bb, else_bb);
 
-        active_func.push_basic_block(program, then_bb);
-        context.push();
-        self.then_block.generate(program, context)?;
-        context.pop();
+        context.active_function_mut().push_basic_block(program, then_bb);
+        self.then_stmt.generate(program, context);
 
         let active_func = context.active_function_mut();
         let then_jump = active_func.create_value(program).jump(next_bb);
         active_func.push_instruction(program, then_jump);
 
         active_func.push_basic_block(program, else_bb);
-        if let Some(else_block) = &self.else_block {
-            context.push();
-            else_block.generate(program, context)?;
-            context.pop();
+        if let Some(else_stmt) = &self.else_stmt {
+            else_stmt.generate(program, context);
         }
         let active_func = context.active_function_mut();
         let else_jump = active_func.create_value(program).jump(next_bb);
         active_func.push_instruction(program, else_jump);
-        
+
         active_func.push_basic_block(program, next_bb);
-        Ok(())
     }
 }
 
 impl<'ast> GenerateIR<'ast> for While {
     type Out = ();
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
         let active_func = context.active_function_mut();
         let entry_bb = active_func.create_basic_block(program, "%while_entry".into());
+        let entry_jump = active_func.create_value(program).jump(entry_bb);
+        active_func.push_instruction(program, entry_jump);
         active_func.push_basic_block(program, entry_bb);
-        let cond = self.cond.generate(program, context)?;
 
-        let active_func = context.active_function_mut();
         let body_bb = active_func.create_basic_block(program, "%while_body".into());
         let next_bb = active_func.create_basic_block(program, "%body".into());
-        let br = active_func.create_value(program).branch(cond, body_bb, next_bb);
-        active_func.push_instruction(program, br);
+        self.cond.generate_cond(program, context, body_bb, next_bb);
 
-        active_func.push_basic_block(program, body_bb);
-        context.push();
-        self.block.generate(program, context)?;
-        context.pop();
+        context.active_function_mut().push_basic_block(program, body_bb);
+        context.push_loop(entry_bb, next_bb);
+        self.body.generate(program, context);
+        context.pop_loop();
 
         let active_func = context.active_function_mut();
         let jump = active_func.create_value(program).jump(entry_bb);
         active_func.push_instruction(program, jump);
 
         active_func.push_basic_block(program, next_bb);
-        Ok(())
     }
 }
 
-impl<'ast> GenerateIR<'ast> for Return {
+impl<'ast> GenerateIR<'ast> for Break {
     type Out = ();
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        let exp = self.exp.generate(program, context)?;
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let (_, exit_bb) = context.active_loop().unwrap();
+        let active_func = context.active_function_mut();
+        let jump = active_func.create_value(program).jump(exit_bb);
+        active_func.push_instruction(program, jump);
 
+        // code after `break` is unreachable, but still needs a block to live in
+        let next_bb = active_func.create_basic_block(program, "%after_break".into());
+        active_func.push_basic_block(program, next_bb);
+    }
+}
+
+impl<'ast> GenerateIR<'ast> for Continue {
+    type Out = ();
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let (entry_bb, _) = context.active_loop().unwrap();
         let active_func = context.active_function_mut();
-        let jump = active_func.create_value(program).jump(active_func.end());
-        active_func.finish_allocate(program);
+        let jump = active_func.create_value(program).jump(entry_bb);
         active_func.push_instruction(program, jump);
-        active_func.push_basic_block(program, active_func.end());
 
-        let ret_val = active_func.return_value();
-        let store = active_func.create_value(program).store(exp, ret_val);
-        active_func.push_instruction(program, store);
+        // code after `continue` is unreachable, but still needs a block to live in
+        let next_bb = active_func.create_basic_block(program, "%after_continue".into());
+        active_func.push_basic_block(program, next_bb);
+    }
+}
 
-        let ret = active_func.create_value(program).ret(Some(ret_val));
-        active_func.push_instruction(program, ret);
-        Ok(())
+impl<'ast> GenerateIR<'ast> for Return {
+    type Out = ();
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let exp = self.exp.as_ref().map(|exp| exp.generate(program, context));
+        context.active_function_mut().generate_return(program, exp);
     }
 }
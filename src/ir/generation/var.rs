@@ -1,53 +1,270 @@
 use crate::ir::GenerateIR;
-use crate::ir::Result;
 use crate::ir::context::Context;
 use crate::ast::var::*;
-use koopa::ir::builder::LocalInstBuilder;
-use koopa::ir::{Program, Value, Type};
+use crate::sema::{flatten, Evaluate, SymbolKind, Type as SysyType};
+use koopa::ir::builder_traits::*;
+use koopa::ir::{Program, Value, Type, TypeKind};
+use koopa::ir::values::BinaryOp;
 
-impl<'ast> GenerateIR<'ast> for Decl {
+impl<'ast> GenerateIR<'ast> for ConstDecl {
     type Out = ();
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let base = self.decl_type.generate(program, context);
         for def in &self.defs {
-            def.generate(program, context)?;
+            let symbol = context.analysis.def(def.span);
+            let SymbolKind::Const { ty, elems } = &context.analysis.symbol(symbol).kind else {
+                unreachable!()
+            };
+            // scalar constants are always evaluated, only arrays need to be stored
+            let SysyType::Array(dims) = ty else {
+                continue;
+            };
+
+            let alloc = if context.is_global() {
+                let init = create_aggregate(program, dims, elems);
+                create_global_alloc(program, init, &def.id)
+            } else {
+                let active_func = context.active_function();
+                let alloc = active_func.allocate(program, array_type(base.clone(), dims), Some(&def.id));
+                let vals = elems
+                    .iter()
+                    .map(|&elem| (elem != 0).then(|| active_func.create_value(program).integer(elem)))
+                    .collect();
+                store_elements(program, context, alloc, dims, vals);
+                alloc
+            };
+            context.insert_value(symbol, alloc);
         }
-        Ok(())
     }
 }
 
-impl<'ast> GenerateIR<'ast> for Def {
+impl<'ast> GenerateIR<'ast> for Decl {
     type Out = ();
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        let val = self.val.as_ref().map(|v| v.generate(program, context));
-        let active_func = context.active_function();
-        let alloc = active_func.allocate(program, Type::get_i32(), Some(&self.id));
-        if let Some(val) = val {
-            let store = active_func.create_value(program).store(val?, alloc);
-            active_func.push_instruction(program, store);
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let base = self.decl_type.generate(program, context);
+        for def in &self.defs {
+            let symbol = context.analysis.def(def.span);
+            let SymbolKind::Var { ty, init } = &context.analysis.symbol(symbol).kind else {
+                unreachable!()
+            };
+            let dims: &[usize] = match ty {
+                SysyType::Array(dims) => dims,
+                _ => &[],
+            };
+            if context.is_global() {
+                let init = match init {
+                    Some(elems) => create_aggregate(program, dims, elems),
+                    None => program.new_value().zero_init(array_type(base.clone(), dims)),
+                };
+                let alloc = create_global_alloc(program, init, &def.id);
+                context.insert_value(symbol, alloc);
+                continue;
+            }
+
+            let vals = def.generate(program, context);
+            let alloc = context.active_function().allocate(program, array_type(base.clone(), dims), Some(&def.id));
+            context.insert_value(symbol, alloc);
+            if def.val.is_some() {
+                store_elements(program, context, alloc, dims, vals);
+            }
         }
-        context.insert_value(&self.id, alloc)?;
-        Ok(())
     }
 }
 
-impl<'ast> GenerateIR<'ast> for InitVal {
-    type Out = Value;
+impl<'ast> GenerateIR<'ast> for Def {
+    /// The row-major element list of the initializer, `None` for the elements known to be zero.
+    type Out = Vec<Option<Value>>;
+
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        let Some(val) = &self.val else {
+            return Vec::new();
+        };
+        let dims = match &context.analysis.symbol(context.analysis.def(self.span)).kind {
+            SymbolKind::Var { ty: SysyType::Array(dims), .. } => dims.as_slice(),
+            _ => &[],
+        };
+        flatten(val, dims)
+            .unwrap_or_else(|_| unreachable!())
+            .into_iter()
+            .map(|exp| match exp {
+                Some(exp) if !matches!(exp.evaluate(context.analysis), Ok(0)) => Some(exp.generate(program, context)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl<'ast> GenerateIR<'ast> for BType {
+    type Out = Type;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
-        self.exp.generate(program, context)
+    fn generate(&'ast self, _: &mut Program, _: &mut Context<'ast>) -> Self::Out {
+        match self {
+            Self::Int => Type::get_i32(),
+        }
     }
 }
 
 impl<'ast> GenerateIR<'ast> for LVal {
     type Out = Value;
 
-    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Result<Self::Out> {
+    fn generate(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Self::Out {
+        // constants with constant subscripts are folded
+        if let Ok(value) = self.evaluate(context.analysis) {
+            return context.active_function().create_value(program).integer(value);
+        }
+
+        let ptr = self.generate_ptr(program, context);
         let active_func = context.active_function();
-        let lval = context.get_value(&self.id)?;
-        let load = active_func.create_value(program).load(lval);
-        active_func.push_instruction(program, load);
-        Ok(load)
+        let value = match active_func.value_type(program, ptr).kind() {
+            TypeKind::Pointer(base) if matches!(base.kind(), TypeKind::Array(..)) => {
+                // arrays decay into the pointer to their first element
+                let zero = active_func.create_value(program).integer(0);
+                active_func.create_value(program).get_elem_ptr(ptr, zero)
+            }
+            _ => active_func.create_value(program).load(ptr),
+        };
+        active_func.push_instruction(program, value);
+        value
+    }
+}
+
+impl LVal {
+    /// Generates the pointer to the object designated by the lvalue.
+    pub fn generate_ptr<'ast>(&'ast self, program: &mut Program, context: &mut Context<'ast>) -> Value {
+        let mut ptr = context.value(context.analysis.resolve(self.span));
+        for index in &self.indices {
+            let index = index.generate(program, context);
+            let active_func = context.active_function();
+            ptr = match active_func.value_type(program, ptr).kind() {
+                TypeKind::Pointer(base) => match base.kind() {
+                    TypeKind::Array(..) => active_func.create_value(program).get_elem_ptr(ptr, index),
+                    // array parameters are passed as pointers
+                    TypeKind::Pointer(_) => {
+                        let load = active_func.create_value(program).load(ptr);
+                        active_func.push_instruction(program, load);
+                        active_func.create_value(program).get_ptr(load, index)
+                    }
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            active_func.push_instruction(program, ptr);
+        }
+        ptr
     }
 }
+
+pub fn array_type(base: Type, dims: &[usize]) -> Type {
+    dims.iter().rev().fold(base, |ty, &len| Type::get_array(ty, len))
+}
+
+fn create_aggregate(program: &mut Program, dims: &[usize], elems: &[i32]) -> Value {
+    if dims.is_empty() {
+        program.new_value().integer(elems[0])
+    } else if elems.iter().all(|&elem| elem == 0) {
+        program.new_value().zero_init(array_type(Type::get_i32(), dims))
+    } else {
+        let stride = elems.len() / dims[0];
+        let elems = elems
+            .chunks(stride)
+            .map(|elems| create_aggregate(program, &dims[1..], elems))
+            .collect();
+        program.new_value().aggregate(elems)
+    }
+}
+
+fn create_global_alloc(program: &mut Program, init: Value, id: &str) -> Value {
+    let alloc = program.new_value().global_alloc(init);
+    program.set_value_name(alloc, Some(format!("@{}", id)));
+    alloc
+}
+
+/// Zero elements are stored one by one up to this many, and zeroed in a loop beyond.
+const MAX_ZERO_STORES: usize = 16;
+
+/// Stores the row-major element list to the array allocated by `alloc`, where `None`
+/// stands for zero.
+fn store_elements(
+    program: &mut Program,
+    context: &mut Context,
+    alloc: Value,
+    dims: &[usize],
+    vals: Vec<Option<Value>>,
+) {
+    let zeroed = vals.iter().filter(|val| val.is_none()).count() > MAX_ZERO_STORES;
+    if zeroed {
+        zero_elements(program, context, alloc, dims, vals.len());
+    }
+    let active_func = context.active_function();
+    // pointers to the sub-arrays of the previous element
+    let mut ptrs = vec![alloc];
+    let mut last: Vec<usize> = Vec::new();
+    for (i, val) in vals.into_iter().enumerate() {
+        let val = match val {
+            Some(val) => val,
+            None if zeroed => continue,
+            None => active_func.create_value(program).integer(0),
+        };
+        let mut indices = Vec::with_capacity(dims.len());
+        let mut rest = i;
+        for &dim in dims.iter().rev() {
+            indices.push(rest % dim);
+            rest /= dim;
+        }
+        indices.reverse();
+
+        let same = indices.iter().zip(&last).take_while(|(a, b)| a == b).count();
+        ptrs.truncate(same + 1);
+        for &index in &indices[same..] {
+            let index = active_func.create_value(program).integer(index as i32);
+            let ptr = active_func.create_value(program).get_elem_ptr(*ptrs.last().unwrap(), index);
+            active_func.push_instruction(program, ptr);
+            ptrs.push(ptr);
+        }
+        let store = active_func.create_value(program).store(val, *ptrs.last().unwrap());
+        active_func.push_instruction(program, store);
+        last = indices;
+    }
+}
+
+/// Zeroes the `len` elements of the array allocated by `alloc` in a loop.
+fn zero_elements(program: &mut Program, context: &mut Context, alloc: Value, dims: &[usize], len: usize) {
+    let active_func = context.active_function_mut();
+    let mut first = alloc;
+    for _ in dims {
+        let zero = active_func.create_value(program).integer(0);
+        first = active_func.create_value(program).get_elem_ptr(first, zero);
+        active_func.push_instruction(program, first);
+    }
+    let counter = active_func.allocate(program, Type::get_i32(), None);
+    let zero = active_func.create_value(program).integer(0);
+    let store = active_func.create_value(program).store(zero, counter);
+    active_func.push_instruction(program, store);
+    let body_bb = active_func.create_basic_block(program, "%zero_body".into());
+    let end_bb = active_func.create_basic_block(program, "%zero_end".into());
+    let jump = active_func.create_value(program).jump(body_bb);
+    active_func.push_instruction(program, jump);
+
+    active_func.push_basic_block(program, body_bb);
+    let index = active_func.create_value(program).load(counter);
+    active_func.push_instruction(program, index);
+    let ptr = active_func.create_value(program).get_ptr(first, index);
+    active_func.push_instruction(program, ptr);
+    let zero = active_func.create_value(program).integer(0);
+    let store = active_func.create_value(program).store(zero, ptr);
+    active_func.push_instruction(program, store);
+    let one = active_func.create_value(program).integer(1);
+    let next = active_func.create_value(program).binary(BinaryOp::Add, index, one);
+    active_func.push_instruction(program, next);
+    let store = active_func.create_value(program).store(next, counter);
+    active_func.push_instruction(program, store);
+    let len = active_func.create_value(program).integer(len as i32);
+    let cond = active_func.create_value(program).binary(BinaryOp::Lt, next, len);
+    active_func.push_instruction(program, cond);
+    let br = active_func.create_value(program).branch(cond, body_bb, end_bb);
+    active_func.push_instruction(program, br);
+
+    active_func.push_basic_block(program, end_bb);
+}
@@ -0,0 +1,33 @@
+use crate::ast::{CompUnit, GlobalItem};
+use crate::ir::context::Context;
+use crate::ir::generation::koopa_type;
+use crate::sema::SymbolKind;
+use koopa::ir::{FunctionData, Program};
+use std::collections::HashSet;
+
+/// Declares the functions that the program does not define: the whole runtime library,
+/// except the names the program defines itself, and the functions only given prototypes.
+pub fn declare_library(program: &mut Program, context: &mut Context, comp_unit: &CompUnit) {
+    let defined: HashSet<&str> = comp_unit
+        .items
+        .iter()
+        .flat_map(|item| match item {
+            GlobalItem::ConstDecl(decl) => decl.defs.iter().map(|def| def.id.as_str()).collect(),
+            GlobalItem::Decl(decl) => decl.defs.iter().map(|def| def.id.as_str()).collect(),
+            GlobalItem::FuncDef(func_def) => vec![func_def.ident.as_str()],
+        })
+        .collect();
+    let analysis = context.analysis;
+    let library = analysis.symbols().filter(|(_, symbol)| match symbol.kind {
+        SymbolKind::Function { defined: false, .. } => symbol.span.is_some() || !defined.contains(symbol.name.as_str()),
+        _ => false,
+    });
+    for (id, symbol) in library {
+        let SymbolKind::Function { params, ret, .. } = &symbol.kind else {
+            unreachable!()
+        };
+        let params = params.iter().map(koopa_type).collect();
+        let data = FunctionData::new_decl(format!("@{}", symbol.name), params, koopa_type(ret));
+        context.insert_function(id, program.new_func(data));
+    }
+}
@@ -1,39 +1,25 @@
 mod generation;
 mod context;
 mod function;
-
-use core::fmt;
+mod library;
 
 use crate::ast::CompUnit;
+use crate::sema::Analysis;
+use crate::source::Span;
 use context::Context;
 use generation::GenerateIR;
-use koopa::ir::Program;
-
-pub type Result<T> = std::result::Result<T, Error>;
+use koopa::ir::{Program, Value};
+use std::collections::HashMap;
 
-pub enum Error {
-    Unknown,
-    DuplicateDefinition,
-    SymbolNotFound,
-    ReturnInVoidFunction,
-    UseVoidValue,
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::Unknown => write!(f, "unknown error"),
-            Self::DuplicateDefinition => write!(f, "duplicate definition"),
-            Self::SymbolNotFound => write!(f, "symbol not found"),
-            Self::ReturnInVoidFunction => write!(f, "return in void function"),
-            Self::UseVoidValue => write!(f, "use void value"),
-        }
-    }
-}
+/// The source spans of the instructions which later passes may report about,
+/// those are divisions so far.
+pub type Locations = HashMap<Value, Span>;
 
-pub fn generate_program(comp_unit: &CompUnit) -> Result<Program> {
+/// Generates the Koopa IR program, which must have passed the semantic analysis.
+pub fn generate_program(comp_unit: &CompUnit, analysis: &Analysis) -> (Program, Locations) {
     let mut program = Program::new();
-    comp_unit.generate(&mut program, &mut Context::new())?;
-    println!("{:#?}", comp_unit);
-    Ok(program)
+    let mut context = Context::new(analysis);
+    library::declare_library(&mut program, &mut context, comp_unit);
+    comp_unit.generate(&mut program, &mut context);
+    (program, context.locations)
 }
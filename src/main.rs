@@ -1,12 +1,18 @@
+mod preprocess;
 mod parser;
 mod ast;
+mod sema;
 mod ir;
+mod opt;
 mod codegen;
+mod source;
 
-use parser::sysy::CompUnitParser;
+use source::SourceMap;
+use sema::WarningOptions;
 use koopa::back::KoopaGenerator;
 use std::env::args;
 use std::fs::read_to_string;
+use std::path::Path;
 use std::process::exit;
 use std::io;
 use std::fmt;
@@ -23,29 +29,95 @@ fn run() -> Result<(), Error> {
         mode,
         input,
         output,
+        warnings,
+        optimize,
     } = CommandLineArgs::parse()?;
 
-    let input = read_to_string(input).map_err(Error::File)?;
-    let comp_unit = CompUnitParser::new()
-        .parse(&input)
-        .map_err(|_| Error::Parse)?;
-    let program = ir::generate_program(&comp_unit).map_err(Error::Generate)?;
+    let text = read_to_string(&input).map_err(Error::File)?;
+    let preprocessed = preprocess::preprocess(Path::new(&input), text);
+    let source_map = SourceMap::new(&preprocessed.text, &preprocessed.origins, &preprocessed.files);
+    if !preprocessed.errors.is_empty() {
+        let errors = preprocessed
+            .errors
+            .iter()
+            .map(|err| source_map.error_at(err.origin, err.columns.clone(), &err.message))
+            .collect();
+        return Err(Error::Preprocess(errors));
+    }
+    let comp_unit = parser::parse(&preprocessed.text).map_err(|errors| {
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|err| source_map.error(err.span, &err.message))
+            .collect();
+        Error::Parse(errors)
+    })?;
+    let analysis = sema::analyze(&comp_unit).map_err(|errors| {
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|err| {
+                let mut message = match err.span {
+                    Some(span) => source_map.error(span, &err.kind),
+                    // errors about the whole program
+                    None => format!("error: {}", err.kind),
+                };
+                if let Some((span, note)) = err.previous {
+                    message.push('\n');
+                    message.push_str(&source_map.note(span, &note));
+                }
+                message
+            })
+            .collect();
+        Error::Semantic(errors)
+    })?;
+    let (mut program, locations) = ir::generate_program(&comp_unit, &analysis);
+    let opt_warnings = if optimize {
+        opt::optimize(&mut program, &locations)
+    } else {
+        Vec::new()
+    };
+    // reported in the order of the source, wherever they were found
+    let mut found: Vec<_> = analysis.warnings().iter().chain(&opt_warnings).collect();
+    found.sort_by_key(|warning| warning.span.start);
+    let diagnostics: Vec<_> = found
+        .into_iter()
+        .filter(|warning| warnings.enabled(warning.kind.lint()))
+        .map(|warning| {
+            let name = warning.kind.lint().name();
+            let mut message = if warnings.error {
+                source_map.error(warning.span, &format_args!("{} [-Werror={}]", warning.kind, name))
+            } else {
+                source_map.warning(warning.span, &format_args!("{} [-W{}]", warning.kind, name))
+            };
+            if let Some((span, note)) = warning.note {
+                message.push('\n');
+                message.push_str(&source_map.note(span, &note));
+            }
+            message
+        })
+        .collect();
+    if warnings.error && !diagnostics.is_empty() {
+        return Err(Error::Semantic(diagnostics));
+    }
+    for diagnostic in diagnostics {
+        eprintln!("{}\n", diagnostic);
+    }
 
     if matches!(mode, Mode::Koopa) {
-        return KoopaGenerator::from_path(output)
+        KoopaGenerator::from_path(output)
         .map_err(Error::File)?
         .generate_on(&program)
-        .map_err(Error::Io);
+        .map_err(Error::Io)
     } else {
-        return codegen::generate_asm(&program, &output).map_err(Error::Io);
+        codegen::generate_asm(&program, &output).map_err(Error::Io)
     }
 }
 
 enum Error {
     InvalidArgs,
     File(io::Error),
-    Parse,
-    Generate(ir::Error),
+    Preprocess(Vec<String>),
+    Parse(Vec<String>),
+    Semantic(Vec<String>),
     Io(io::Error),
 }
 
@@ -54,16 +126,22 @@ impl fmt::Display for Error {
         match self {
         Self::InvalidArgs => write!(
             f,
-            r#"Usage: kira MODE INPUT -o OUTPUT
+            r#"Usage: kira MODE INPUT -o OUTPUT [-O] [WARNINGS...]
 
 Options:
-MODE:   can be `-koopa`, `-riscv`
-INPUT:  the input SysY source file
-OUTPUT: the output file"#
+MODE:     can be `-koopa`, `-riscv`
+INPUT:    the input SysY source file
+OUTPUT:   the output file
+-O:       optimizes the generated IR
+WARNINGS: `-W<name>` or `-Wno-<name>` switches a warning on or off, where
+          name is `unused`, `shadow`, `uninitialized`, `unreachable-code`
+          or `div-by-zero`, which is only found with `-O`,
+          `-Werror` turns warnings into errors"#
     ),
         Self::File(err) => write!(f, "invalid input SysY file: {}", err),
-        Self::Parse => write!(f, "error occurred while parsing"),
-        Self::Generate(err) => write!(f, "{}", err),
+        Self::Preprocess(errors) => write!(f, "{}", errors.join("\n\n")),
+        Self::Parse(errors) => write!(f, "{}", errors.join("\n\n")),
+        Self::Semantic(errors) => write!(f, "{}", errors.join("\n\n")),
         Self::Io(err) => write!(f, "I/O error: {}", err),
         }
     }
@@ -73,12 +151,26 @@ struct CommandLineArgs {
     mode: Mode,
     input: String,
     output: String,
+    warnings: WarningOptions,
+    optimize: bool,
 }
 
 impl CommandLineArgs {
     fn parse() -> Result<Self, Error> {
-        let mut args = args();
-        args.next();
+        // flags may appear anywhere, the rest are positional
+        let (flags, args): (Vec<_>, Vec<_>) = args()
+            .skip(1)
+            .partition(|arg| arg.starts_with("-W") || arg == "-O");
+        let mut warnings = WarningOptions::default();
+        let mut optimize = false;
+        for flag in flags {
+            match flag.as_str() {
+                "-O" => optimize = true,
+                flag if warnings.parse_flag(flag) => {}
+                _ => return Err(Error::InvalidArgs),
+            }
+        }
+        let mut args = args.into_iter();
         match (args.next(), args.next(), args.next(), args.next()) {
             (Some(m), Some(input), Some(o), Some(output)) if o == "-o" => {
                 let mode = match m.as_str() {
@@ -90,6 +182,8 @@ impl CommandLineArgs {
                 mode,
                 input,
                 output,
+                warnings,
+                optimize,
                 })
             }
             _ => Err(Error::InvalidArgs),
@@ -0,0 +1,148 @@
+use crate::opt::rewrite::remove_insts;
+use koopa::ir::{BasicBlock, FunctionData, Value, ValueKind};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Returns the last instruction of the basic block, which ends it.
+pub fn terminator(data: &FunctionData, bb: BasicBlock) -> Option<Value> {
+    data.layout().bbs().node(&bb)?.insts().back_key().copied()
+}
+
+/// Returns the basic blocks the basic block may jump to.
+pub fn successors(data: &FunctionData, bb: BasicBlock) -> Vec<BasicBlock> {
+    let Some(term) = terminator(data, bb) else {
+        return Vec::new();
+    };
+    match data.dfg().value(term).kind() {
+        ValueKind::Branch(branch) => vec![branch.true_bb(), branch.false_bb()],
+        ValueKind::Jump(jump) => vec![jump.target()],
+        _ => Vec::new(),
+    }
+}
+
+/// Removes the basic blocks unreachable from the entry, returns whether there were any.
+pub fn remove_unreachable(data: &mut FunctionData) -> bool {
+    let cfg = Cfg::new(data);
+    let unreachable: Vec<_> = data.layout().bbs().keys().copied().filter(|&bb| !cfg.is_reachable(bb)).collect();
+    let insts = unreachable
+        .iter()
+        .flat_map(|bb| data.layout().bbs().node(bb).unwrap().insts().keys().copied())
+        .collect();
+    remove_insts(data, insts);
+    for &bb in &unreachable {
+        data.layout_mut().bbs_mut().remove(&bb);
+        data.dfg_mut().remove_bb(bb);
+    }
+    !unreachable.is_empty()
+}
+
+/// The control flow graph of a function, made of the basic blocks reachable from the entry.
+pub struct Cfg {
+    /// The reachable basic blocks in reverse postorder, the entry first.
+    pub order: Vec<BasicBlock>,
+    pub preds: HashMap<BasicBlock, Vec<BasicBlock>>,
+    pub succs: HashMap<BasicBlock, Vec<BasicBlock>>,
+}
+
+impl Cfg {
+    /// Builds the graph of a function which has a body.
+    pub fn new(data: &FunctionData) -> Self {
+        let entry = data.layout().entry_bb().unwrap();
+        let mut succs = HashMap::new();
+        let mut postorder = Vec::new();
+        // the second field is the index of the next successor to visit
+        let mut stack = vec![(entry, 0)];
+        succs.insert(entry, successors(data, entry));
+        while let Some((bb, next)) = stack.pop() {
+            match succs[&bb].get(next).copied() {
+                Some(succ) => {
+                    stack.push((bb, next + 1));
+                    if let Entry::Vacant(entry) = succs.entry(succ) {
+                        entry.insert(successors(data, succ));
+                        stack.push((succ, 0));
+                    }
+                }
+                None => postorder.push(bb),
+            }
+        }
+        let mut preds: HashMap<_, Vec<_>> = postorder.iter().map(|&bb| (bb, Vec::new())).collect();
+        let order: Vec<_> = postorder.into_iter().rev().collect();
+        for &bb in &order {
+            for &succ in &succs[&bb] {
+                preds.get_mut(&succ).unwrap().push(bb);
+            }
+        }
+        Self { order, preds, succs }
+    }
+
+    pub fn is_reachable(&self, bb: BasicBlock) -> bool {
+        self.succs.contains_key(&bb)
+    }
+
+    /// Computes the dominator tree with the algorithm of Cooper, Harvey and Kennedy.
+    pub fn dominators(&self) -> Dominators {
+        let index: HashMap<_, _> = self.order.iter().enumerate().map(|(i, &bb)| (bb, i)).collect();
+        let entry = self.order[0];
+        let mut idom = HashMap::from([(entry, entry)]);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &bb in &self.order[1..] {
+                let mut preds = self.preds[&bb].iter().filter(|pred| idom.contains_key(*pred));
+                let mut new_idom = *preds.next().unwrap();
+                for &pred in preds {
+                    // walk up from both until the paths meet
+                    let mut other = pred;
+                    while new_idom != other {
+                        while index[&new_idom] > index[&other] {
+                            new_idom = idom[&new_idom];
+                        }
+                        while index[&other] > index[&new_idom] {
+                            other = idom[&other];
+                        }
+                    }
+                }
+                if idom.insert(bb, new_idom) != Some(new_idom) {
+                    changed = true;
+                }
+            }
+        }
+
+        let mut frontiers: HashMap<_, Vec<_>> = self.order.iter().map(|&bb| (bb, Vec::new())).collect();
+        for &bb in &self.order {
+            let preds = &self.preds[&bb];
+            if preds.len() < 2 {
+                continue;
+            }
+            for &pred in preds {
+                let mut runner = pred;
+                while runner != idom[&bb] {
+                    let frontier = frontiers.get_mut(&runner).unwrap();
+                    if !frontier.contains(&bb) {
+                        frontier.push(bb);
+                    }
+                    runner = idom[&runner];
+                }
+            }
+        }
+        Dominators { idom, frontiers }
+    }
+}
+
+pub struct Dominators {
+    /// The immediate dominator of each reachable basic block, the entry dominates itself.
+    idom: HashMap<BasicBlock, BasicBlock>,
+    frontiers: HashMap<BasicBlock, Vec<BasicBlock>>,
+}
+
+impl Dominators {
+    /// Returns the immediate dominator of the basic block, `None` for the entry.
+    pub fn idom(&self, bb: BasicBlock) -> Option<BasicBlock> {
+        self.idom.get(&bb).copied().filter(|&idom| idom != bb)
+    }
+
+    /// Returns the basic blocks where the dominance of the basic block ends.
+    pub fn frontier(&self, bb: BasicBlock) -> &[BasicBlock] {
+        &self.frontiers[&bb]
+    }
+}
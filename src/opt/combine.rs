@@ -0,0 +1,149 @@
+use crate::opt::rewrite::{remove_insts, replace_uses};
+use koopa::ir::builder_traits::*;
+use koopa::ir::values::BinaryOp;
+use koopa::ir::{Function, FunctionData, Value, ValueKind};
+use koopa::opt::FunctionPass;
+use std::collections::HashMap;
+
+/// Combines instructions with algebraic identities which hold under wrapping arithmetic,
+/// until none applies any more.
+pub struct Combine;
+
+impl FunctionPass for Combine {
+    fn run_on(&mut self, _: Function, data: &mut FunctionData) {
+        loop {
+            let mut replaced = HashMap::new();
+            let insts: Vec<_> = data.layout().bbs().nodes().flat_map(|node| node.insts().keys().copied()).collect();
+            for inst in insts {
+                let ValueKind::Binary(binary) = data.dfg().value(inst).kind() else {
+                    continue;
+                };
+                // an operand replaced in this round is only looked through in the next one
+                if replaced.contains_key(&binary.lhs()) || replaced.contains_key(&binary.rhs()) {
+                    continue;
+                }
+                let value = match combine(data, binary.op(), binary.lhs(), binary.rhs()) {
+                    Some(Combined::Value(value)) => value,
+                    Some(Combined::Const(constant)) => data.dfg_mut().new_value().integer(constant),
+                    Some(Combined::Binary(op, lhs, rhs)) => {
+                        let value = data.dfg_mut().new_value().binary(op, lhs, rhs);
+                        let bb = data.layout().parent_bb(inst).unwrap();
+                        data.layout_mut().bb_mut(bb).insts_mut().cursor_mut(inst).insert_key_before(value).unwrap();
+                        value
+                    }
+                    None => continue,
+                };
+                replaced.insert(inst, value);
+            }
+            if replaced.is_empty() {
+                break;
+            }
+            // an instruction may be combined to what looks through another one replaced in this round
+            let resolved: HashMap<_, _> = replaced
+                .keys()
+                .map(|&inst| {
+                    let mut value = replaced[&inst];
+                    while let Some(&next) = replaced.get(&value) {
+                        value = next;
+                    }
+                    (inst, value)
+                })
+                .collect();
+            replace_uses(data, &resolved);
+            remove_insts(data, resolved.into_keys().collect());
+        }
+    }
+}
+
+/// What an instruction is combined to.
+enum Combined {
+    Value(Value),
+    Const(i32),
+    Binary(BinaryOp, Value, Value),
+}
+
+fn constant(data: &FunctionData, value: Value) -> Option<i32> {
+    if value.is_global() {
+        return None;
+    }
+    match data.dfg().value(value).kind() {
+        ValueKind::Integer(integer) => Some(integer.value()),
+        _ => None,
+    }
+}
+
+/// Returns the operation and the operands if the value is computed by a binary instruction.
+fn binary(data: &FunctionData, value: Value) -> Option<(BinaryOp, Value, Value)> {
+    if value.is_global() {
+        return None;
+    }
+    match data.dfg().value(value).kind() {
+        ValueKind::Binary(binary) => Some((binary.op(), binary.lhs(), binary.rhs())),
+        _ => None,
+    }
+}
+
+fn is_commutative(op: BinaryOp) -> bool {
+    use BinaryOp::*;
+    matches!(op, NotEq | Eq | Add | Mul | And | Or | Xor)
+}
+
+/// Returns the comparison which is true exactly when `op` is false.
+fn inverse(op: BinaryOp) -> Option<BinaryOp> {
+    use BinaryOp::*;
+    Some(match op {
+        NotEq => Eq,
+        Eq => NotEq,
+        Gt => Le,
+        Lt => Ge,
+        Ge => Lt,
+        Le => Gt,
+        _ => return None,
+    })
+}
+
+/// Returns whether the value is known to be either 0 or 1.
+fn is_boolean(data: &FunctionData, value: Value) -> bool {
+    matches!(constant(data, value), Some(0 | 1))
+        || binary(data, value).is_some_and(|(op, ..)| inverse(op).is_some())
+}
+
+/// Builds a binary instruction with the constant operand on the right if the operation allows.
+fn canonical(data: &FunctionData, op: BinaryOp, lhs: Value, rhs: Value) -> Combined {
+    if is_commutative(op) && constant(data, lhs).is_some() && constant(data, rhs).is_none() {
+        Combined::Binary(op, rhs, lhs)
+    } else {
+        Combined::Binary(op, lhs, rhs)
+    }
+}
+
+fn combine(data: &FunctionData, op: BinaryOp, lhs: Value, rhs: Value) -> Option<Combined> {
+    use BinaryOp::*;
+    // -(-x), as negation is lowered to `sub 0, x`
+    if op == Sub && constant(data, lhs) == Some(0) {
+        if let Some((Sub, zero, x)) = binary(data, rhs) {
+            if constant(data, zero) == Some(0) {
+                return Some(Combined::Value(x));
+            }
+        }
+    }
+    let (lhs, rhs) = match is_commutative(op) && constant(data, lhs).is_some() {
+        true => (rhs, lhs),
+        false => (lhs, rhs),
+    };
+    Some(match (op, constant(data, rhs)) {
+        (Add | Sub | Or | Xor | Shl | Shr | Sar, Some(0)) => Combined::Value(lhs),
+        (Mul | Div, Some(1)) => Combined::Value(lhs),
+        (Mul | And, Some(0)) => Combined::Const(0),
+        (Sub | Xor | NotEq | Lt | Gt, _) if lhs == rhs => Combined::Const(0),
+        (Eq | Le | Ge, _) if lhs == rhs => Combined::Const(1),
+        // comparisons of booleans with what they already are
+        (NotEq, Some(0)) | (Eq, Some(1)) if is_boolean(data, lhs) => Combined::Value(lhs),
+        // `!` is lowered to `eq 0, x`, so `!!x` becomes `ne x, 0`
+        (Eq, Some(0)) => {
+            let (op, lhs, rhs) = binary(data, lhs)?;
+            canonical(data, inverse(op)?, lhs, rhs)
+        }
+        _ => return None,
+    })
+}
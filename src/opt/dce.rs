@@ -0,0 +1,170 @@
+use crate::opt::cfg::remove_unreachable;
+use crate::opt::rewrite::{register_uses, remove_insts};
+use koopa::ir::builder_traits::*;
+use koopa::ir::{BasicBlock, Function, FunctionData, Value, ValueKind};
+use koopa::opt::FunctionPass;
+use std::collections::{HashMap, HashSet};
+
+/// Removes the basic blocks unreachable from the entry, then the instructions and basic
+/// block parameters whose results are never needed by a call, a store or a terminator.
+pub struct Dce;
+
+impl FunctionPass for Dce {
+    fn run_on(&mut self, _: Function, data: &mut FunctionData) {
+        // skip declarations
+        if data.layout().entry_bb().is_none() {
+            return;
+        }
+        remove_unreachable(data);
+        let live = mark(data);
+        sweep(data, &live);
+    }
+}
+
+/// Returns whether the instruction has effects other than its result.
+fn has_side_effect(kind: &ValueKind) -> bool {
+    matches!(
+        kind,
+        ValueKind::Store(_) | ValueKind::Call(_) | ValueKind::Return(_) | ValueKind::Jump(_) | ValueKind::Branch(_)
+    )
+}
+
+/// Returns the instructions and basic block parameters whose results are needed, starting
+/// from the instructions with side effects. The arguments of a jump are only needed if the
+/// parameters they are passed to are.
+fn mark(data: &FunctionData) -> HashSet<Value> {
+    let mut params = HashMap::new();
+    for &bb in data.layout().bbs().keys() {
+        for (index, &param) in data.dfg().bb(bb).params().iter().enumerate() {
+            params.insert(param, (bb, index));
+        }
+    }
+
+    let mut live = HashSet::new();
+    let mut worklist = Vec::new();
+    for node in data.layout().bbs().nodes() {
+        for &inst in node.insts().keys() {
+            if has_side_effect(data.dfg().value(inst).kind()) {
+                live.insert(inst);
+                worklist.push(inst);
+            }
+        }
+    }
+    while let Some(value) = worklist.pop() {
+        let used: Vec<Value> = match (params.get(&value), data.dfg().value(value).kind()) {
+            (Some(&(bb, index)), _) => args_passed(data, bb).into_iter().map(|args| args[index]).collect(),
+            (None, ValueKind::Jump(_)) => Vec::new(),
+            (None, ValueKind::Branch(branch)) => vec![branch.cond()],
+            (None, kind) => kind.value_uses().collect(),
+        };
+        for value in used {
+            let tracked = !value.is_global()
+                && (params.contains_key(&value) || data.layout().parent_bb(value).is_some());
+            if tracked && live.insert(value) {
+                worklist.push(value);
+            }
+        }
+    }
+    live
+}
+
+/// Returns the argument lists passed to the parameters of the basic block.
+fn args_passed(data: &FunctionData, bb: BasicBlock) -> Vec<Vec<Value>> {
+    let mut passed = Vec::new();
+    for &user in data.dfg().bb(bb).used_by() {
+        match data.dfg().value(user).kind() {
+            ValueKind::Jump(jump) => passed.push(jump.args().to_vec()),
+            ValueKind::Branch(branch) => {
+                if branch.true_bb() == bb {
+                    passed.push(branch.true_args().to_vec());
+                }
+                if branch.false_bb() == bb {
+                    passed.push(branch.false_args().to_vec());
+                }
+            }
+            _ => {}
+        }
+    }
+    passed
+}
+
+/// Removes what is not live, the parameters along with the arguments passed to them.
+fn sweep(data: &mut FunctionData, live: &HashSet<Value>) {
+    let dead: Vec<_> = data
+        .layout()
+        .bbs()
+        .nodes()
+        .flat_map(|node| node.insts().keys().copied())
+        .filter(|inst| !live.contains(inst))
+        .collect();
+    // the indices of the parameters kept in each block which loses some
+    let mut kept = HashMap::new();
+    for &bb in data.layout().bbs().keys() {
+        let params = data.dfg().bb(bb).params();
+        if params.iter().any(|param| !live.contains(param)) {
+            let indices: Vec<_> = (0..params.len()).filter(|&i| live.contains(&params[i])).collect();
+            kept.insert(bb, indices);
+        }
+    }
+
+    // the arguments are checked against the parameters when terminators are built
+    let mut dead_params = Vec::new();
+    for (&bb, indices) in &kept {
+        let params = data.dfg().bb(bb).params().to_vec();
+        let live_params = indices.iter().map(|&i| params[i]).collect();
+        dead_params.extend(params.into_iter().filter(|param| !live.contains(param)));
+        *data.dfg_mut().bb_mut(bb).params_mut() = live_params;
+    }
+    let keep = |args: &[Value], bb: BasicBlock| match kept.get(&bb) {
+        Some(indices) => indices.iter().map(|&i| args[i]).collect(),
+        None => args.to_vec(),
+    };
+    for &bb in kept.keys() {
+        let users: Vec<_> = data.dfg().bb(bb).used_by().iter().copied().collect();
+        for user in users {
+            let kind = data.dfg().value(user).kind().clone();
+            match kind {
+                ValueKind::Jump(jump) => {
+                    let args = keep(jump.args(), jump.target());
+                    data.dfg_mut().replace_value_with(user).jump_with_args(jump.target(), args);
+                }
+                ValueKind::Branch(branch) => {
+                    let true_args = keep(branch.true_args(), branch.true_bb());
+                    let false_args = keep(branch.false_args(), branch.false_bb());
+                    data.dfg_mut().replace_value_with(user).branch_with_args(
+                        branch.cond(),
+                        branch.true_bb(),
+                        branch.false_bb(),
+                        true_args,
+                        false_args,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+    remove_insts(data, dead);
+    for param in dead_params {
+        data.dfg_mut().remove_value(param);
+    }
+
+    // the parameters know their own index, which changes for those after a removed one
+    let mut reindexed = false;
+    for (bb, indices) in kept {
+        let params = data.dfg().bb(bb).params().to_vec();
+        for (index, (param, old)) in params.into_iter().zip(indices).enumerate() {
+            if index == old {
+                continue;
+            }
+            let mut value = data.dfg().value(param).clone();
+            if let ValueKind::BlockArgRef(arg) = value.kind_mut() {
+                *arg.index_mut() = index;
+            }
+            data.dfg_mut().replace_value_with(param).raw(value);
+            reindexed = true;
+        }
+    }
+    if reindexed {
+        register_uses(data);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::opt::cfg::{terminator, Cfg, Dominators};
+use crate::opt::rewrite::{remove_inst, replace_uses};
+use koopa::ir::builder_traits::*;
+use koopa::ir::{BasicBlock, Function, FunctionData, Type, TypeKind, Value, ValueKind};
+use koopa::opt::FunctionPass;
+use std::collections::{HashMap, HashSet};
+
+/// Promotes the local variables which are only loaded and stored to SSA values, with
+/// basic block parameters in place of phi nodes.
+pub struct Mem2Reg;
+
+impl FunctionPass for Mem2Reg {
+    fn run_on(&mut self, _: Function, data: &mut FunctionData) {
+        // skip declarations
+        let Some(entry) = data.layout().entry_bb() else {
+            return;
+        };
+        let vars = promotable(data, entry);
+        if vars.is_empty() {
+            return;
+        }
+        let cfg = Cfg::new(data);
+        let dominators = cfg.dominators();
+        let phis = place_phis(data, &cfg, &dominators, &vars);
+        let params = insert_params(data, &cfg, &phis);
+        rename(data, &cfg, &dominators, &vars, &phis, &params);
+    }
+}
+
+/// Returns the allocations in the entry block which are only loaded and stored to.
+fn promotable(data: &FunctionData, entry: BasicBlock) -> Vec<Value> {
+    let insts = data.layout().bbs().node(&entry).unwrap().insts();
+    insts
+        .keys()
+        .copied()
+        .filter(|&inst| {
+            let value = data.dfg().value(inst);
+            matches!(value.kind(), ValueKind::Alloc(_))
+                && value.used_by().iter().all(|&user| match data.dfg().value(user).kind() {
+                    ValueKind::Load(_) => true,
+                    ValueKind::Store(store) => store.dest() == inst && store.value() != inst,
+                    _ => false,
+                })
+        })
+        .collect()
+}
+
+/// Returns the type of the values stored in the variable.
+fn var_type(data: &FunctionData, var: Value) -> Type {
+    match data.dfg().value(var).ty().kind() {
+        TypeKind::Pointer(base) => base.clone(),
+        _ => unreachable!(),
+    }
+}
+
+/// Finds where each variable needs a phi node. They are placed in the iterated dominance
+/// frontiers of the stores, where the variable is live.
+fn place_phis(
+    data: &FunctionData,
+    cfg: &Cfg,
+    dominators: &Dominators,
+    vars: &[Value],
+) -> HashMap<BasicBlock, Vec<Value>> {
+    let var_set: HashSet<_> = vars.iter().copied().collect();
+    let mut defs: HashMap<Value, Vec<BasicBlock>> = HashMap::new();
+    // variables read before they are written in each block, and those written
+    let mut exposed: HashMap<BasicBlock, HashSet<Value>> = HashMap::new();
+    let mut killed: HashMap<BasicBlock, HashSet<Value>> = HashMap::new();
+    for &bb in &cfg.order {
+        let (exposed, killed) = (exposed.entry(bb).or_default(), killed.entry(bb).or_default());
+        for &inst in data.layout().bbs().node(&bb).unwrap().insts().keys() {
+            match data.dfg().value(inst).kind() {
+                ValueKind::Load(load) if var_set.contains(&load.src()) && !killed.contains(&load.src()) => {
+                    exposed.insert(load.src());
+                }
+                ValueKind::Store(store) if var_set.contains(&store.dest()) && killed.insert(store.dest()) => {
+                    defs.entry(store.dest()).or_default().push(bb);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // the variables live at the start of each block, computed backwards to a fixpoint
+    let mut live_in = exposed.clone();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &bb in cfg.order.iter().rev() {
+            let live_out: HashSet<_> = cfg.succs[&bb].iter().flat_map(|succ| &live_in[succ]).copied().collect();
+            let live = live_in.get_mut(&bb).unwrap();
+            for var in live_out {
+                if !killed[&bb].contains(&var) && live.insert(var) {
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    let mut phis: HashMap<BasicBlock, Vec<Value>> = HashMap::new();
+    for &var in vars {
+        let mut worklist = defs.get(&var).cloned().unwrap_or_default();
+        let mut placed = HashSet::new();
+        while let Some(bb) = worklist.pop() {
+            for &frontier in dominators.frontier(bb) {
+                if live_in[&frontier].contains(&var) && placed.insert(frontier) {
+                    phis.entry(frontier).or_default().push(var);
+                    // the phi is a new definition
+                    worklist.push(frontier);
+                }
+            }
+        }
+    }
+    phis
+}
+
+/// Adds a parameter to the blocks for each of their phi nodes, returns the parameters
+/// in the same order.
+fn insert_params(
+    data: &mut FunctionData,
+    cfg: &Cfg,
+    phis: &HashMap<BasicBlock, Vec<Value>>,
+) -> HashMap<BasicBlock, Vec<Value>> {
+    let mut params = HashMap::new();
+    for &bb in &cfg.order {
+        let Some(phis) = phis.get(&bb) else {
+            continue;
+        };
+        // Koopa can only create parameters along with a block, so they are created in a
+        // temporary block, whose parameters are numbered after those the block has
+        let dfg = data.dfg();
+        let mut types: Vec<_> = dfg.bb(bb).params().iter().map(|&param| dfg.value(param).ty().clone()).collect();
+        let existing = types.len();
+        types.extend(phis.iter().map(|&var| var_type(data, var)));
+        let dfg = data.dfg_mut();
+        let temp = dfg.new_bb().basic_block_with_params(None, types);
+        let new = dfg.bb_mut(temp).params_mut().split_off(existing);
+        dfg.remove_bb(temp);
+        for (&param, &var) in new.iter().zip(phis) {
+            let name = dfg.value(var).name().clone();
+            dfg.set_value_name(param, name);
+        }
+        dfg.bb_mut(bb).params_mut().extend(&new);
+        params.insert(bb, new);
+    }
+    params
+}
+
+/// Replaces the loads by the values stored last, then passes the values of the variables
+/// to the phi nodes through the jumps, and removes the variables.
+fn rename(
+    data: &mut FunctionData,
+    cfg: &Cfg,
+    dominators: &Dominators,
+    vars: &[Value],
+    phis: &HashMap<BasicBlock, Vec<Value>>,
+    params: &HashMap<BasicBlock, Vec<Value>>,
+) {
+    // variables are undefined until they are stored to
+    let undefined: HashMap<Value, Value> = vars
+        .iter()
+        .map(|&var| {
+            let ty = var_type(data, var);
+            (var, data.dfg_mut().new_value().undef(ty))
+        })
+        .collect();
+    let mut replaced = HashMap::new();
+    let mut stores = Vec::new();
+    // the values of the variables at the end of each block
+    let mut outs: HashMap<BasicBlock, HashMap<Value, Value>> = HashMap::new();
+    // blocks unreachable from the entry see undefined values as well
+    let unreachable: Vec<_> = data.layout().bbs().keys().copied().filter(|&bb| !cfg.is_reachable(bb)).collect();
+    for bb in cfg.order.iter().chain(&unreachable).copied() {
+        // a variable without a phi node has the value it has at the end of the immediate
+        // dominator, which is visited before in reverse postorder
+        let mut current = match dominators.idom(bb) {
+            Some(idom) => outs[&idom].clone(),
+            None => undefined.clone(),
+        };
+        if let (Some(phis), Some(params)) = (phis.get(&bb), params.get(&bb)) {
+            current.extend(phis.iter().copied().zip(params.iter().copied()));
+        }
+        for &inst in data.layout().bbs().node(&bb).unwrap().insts().keys() {
+            match data.dfg().value(inst).kind() {
+                ValueKind::Load(load) if current.contains_key(&load.src()) => {
+                    replaced.insert(inst, current[&load.src()]);
+                }
+                ValueKind::Store(store) if current.contains_key(&store.dest()) => {
+                    let value = replaced.get(&store.value()).copied().unwrap_or(store.value());
+                    current.insert(store.dest(), value);
+                    stores.push(inst);
+                }
+                _ => {}
+            }
+        }
+        outs.insert(bb, current);
+    }
+
+    // pass the arguments of the phi nodes
+    for (&bb, current) in &outs {
+        let Some(term) = terminator(data, bb) else {
+            continue;
+        };
+        let kind = data.dfg().value(term).kind().clone();
+        match kind {
+            ValueKind::Jump(jump) if phis.contains_key(&jump.target()) => {
+                let mut args = jump.args().to_vec();
+                args.extend(phis[&jump.target()].iter().map(|var| current[var]));
+                data.dfg_mut().replace_value_with(term).jump_with_args(jump.target(), args);
+            }
+            ValueKind::Branch(branch)
+                if phis.contains_key(&branch.true_bb()) || phis.contains_key(&branch.false_bb()) =>
+            {
+                let mut true_args = branch.true_args().to_vec();
+                true_args.extend(phis.get(&branch.true_bb()).into_iter().flatten().map(|var| current[var]));
+                let mut false_args = branch.false_args().to_vec();
+                false_args.extend(phis.get(&branch.false_bb()).into_iter().flatten().map(|var| current[var]));
+                data.dfg_mut().replace_value_with(term).branch_with_args(
+                    branch.cond(),
+                    branch.true_bb(),
+                    branch.false_bb(),
+                    true_args,
+                    false_args,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    replace_uses(data, &replaced);
+    for inst in stores.into_iter().chain(replaced.into_keys()).chain(vars.iter().copied()) {
+        remove_inst(data, inst);
+    }
+}
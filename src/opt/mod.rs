@@ -0,0 +1,49 @@
+mod cfg;
+mod combine;
+mod dce;
+mod mem2reg;
+mod rewrite;
+mod sccp;
+mod simplify_cfg;
+#[cfg(test)]
+mod tests;
+
+use crate::ir::Locations;
+use crate::sema::Warning;
+use combine::Combine;
+use dce::Dce;
+use koopa::ir::{FunctionData, Program};
+use koopa::opt::FunctionPass;
+use mem2reg::Mem2Reg;
+use sccp::Sccp;
+use simplify_cfg::SimplifyCfg;
+
+/// Runs the optimization passes on the program, returns the warnings they found.
+pub fn optimize(program: &mut Program, locations: &Locations) -> Vec<Warning> {
+    let mut sccp = Sccp::new(locations);
+    let funcs: Vec<_> = program.func_layout().to_vec();
+    for func in funcs {
+        let data = program.func_mut(func);
+        Mem2Reg.run_on(func, data);
+        sccp.run_on(func, data);
+        Combine.run_on(func, data);
+        // combining may uncover more constants
+        sccp.run_on(func, data);
+        // simplifying the control flow leaves the conditions of folded branches dead, and
+        // removing them may leave blocks which only jump, so both run until neither removes anything
+        loop {
+            let before = size(data);
+            Dce.run_on(func, data);
+            SimplifyCfg.run_on(func, data);
+            if size(data) == before {
+                break;
+            }
+        }
+    }
+    sccp.warnings
+}
+
+/// Returns the number of basic blocks and instructions in the function.
+fn size(data: &FunctionData) -> usize {
+    data.layout().bbs().nodes().map(|node| 1 + node.insts().len()).sum()
+}
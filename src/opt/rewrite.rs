@@ -0,0 +1,122 @@
+use koopa::ir::builder_traits::*;
+use koopa::ir::{FunctionData, Value, ValueKind};
+use std::collections::{HashMap, HashSet};
+
+/// Applies `f` to every value the instruction uses.
+pub fn map_operands(kind: &mut ValueKind, mut f: impl FnMut(Value) -> Value) {
+    match kind {
+        ValueKind::Load(load) => *load.src_mut() = f(load.src()),
+        ValueKind::Store(store) => {
+            *store.value_mut() = f(store.value());
+            *store.dest_mut() = f(store.dest());
+        }
+        ValueKind::GetPtr(gp) => {
+            *gp.src_mut() = f(gp.src());
+            *gp.index_mut() = f(gp.index());
+        }
+        ValueKind::GetElemPtr(gep) => {
+            *gep.src_mut() = f(gep.src());
+            *gep.index_mut() = f(gep.index());
+        }
+        ValueKind::Binary(binary) => {
+            *binary.lhs_mut() = f(binary.lhs());
+            *binary.rhs_mut() = f(binary.rhs());
+        }
+        ValueKind::Branch(branch) => {
+            *branch.cond_mut() = f(branch.cond());
+            branch.true_args_mut().iter_mut().for_each(|arg| *arg = f(*arg));
+            branch.false_args_mut().iter_mut().for_each(|arg| *arg = f(*arg));
+        }
+        ValueKind::Jump(jump) => jump.args_mut().iter_mut().for_each(|arg| *arg = f(*arg)),
+        ValueKind::Call(call) => call.args_mut().iter_mut().for_each(|arg| *arg = f(*arg)),
+        ValueKind::Return(ret) => *ret.value_mut() = ret.value().map(f),
+        _ => {}
+    }
+}
+
+/// Replaces every use of the keys of `replaced` in the function by the values,
+/// the keys are left for the caller to remove.
+///
+/// Koopa forgets the users of a value once it is replaced, so every instruction is
+/// rebuilt, each before the instructions using it, which then register as its users again.
+pub fn replace_uses(data: &mut FunctionData, replaced: &HashMap<Value, Value>) {
+    if !replaced.is_empty() {
+        rebuild(data, replaced);
+    }
+}
+
+/// Registers the users of the values again, once some of them have been rebuilt in place.
+pub fn register_uses(data: &mut FunctionData) {
+    rebuild(data, &HashMap::new());
+}
+
+fn rebuild(data: &mut FunctionData, replaced: &HashMap<Value, Value>) {
+    for inst in topological_order(data) {
+        if replaced.contains_key(&inst) {
+            continue;
+        }
+        let mut value = data.dfg().value(inst).clone();
+        map_operands(value.kind_mut(), |operand| replaced.get(&operand).copied().unwrap_or(operand));
+        data.dfg_mut().replace_value_with(inst).raw(value);
+    }
+}
+
+/// Returns the instructions of the function, each after the instructions it uses.
+fn topological_order(data: &FunctionData) -> Vec<Value> {
+    let insts: Vec<Value> = data
+        .layout()
+        .bbs()
+        .nodes()
+        .flat_map(|node| node.insts().keys().copied())
+        .collect();
+    let mut visited = HashSet::new();
+    let mut order = Vec::with_capacity(insts.len());
+    for &root in &insts {
+        if !visited.insert(root) {
+            continue;
+        }
+        // the second field tells whether the operands have been pushed
+        let mut stack = vec![(root, false)];
+        while let Some((inst, expanded)) = stack.pop() {
+            if expanded {
+                order.push(inst);
+                continue;
+            }
+            stack.push((inst, true));
+            for operand in data.dfg().value(inst).kind().value_uses() {
+                let is_inst = !operand.is_global() && data.layout().parent_bb(operand).is_some();
+                if is_inst && visited.insert(operand) {
+                    stack.push((operand, false));
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Removes an instruction which is not used any more.
+pub fn remove_inst(data: &mut FunctionData, inst: Value) {
+    let bb = data.layout().parent_bb(inst).unwrap();
+    data.layout_mut().bb_mut(bb).insts_mut().remove(&inst);
+    data.dfg_mut().remove_value(inst);
+}
+
+/// Removes instructions which are not used any more, except by each other.
+pub fn remove_insts(data: &mut FunctionData, insts: Vec<Value>) {
+    for &inst in &insts {
+        let bb = data.layout().parent_bb(inst).unwrap();
+        data.layout_mut().bb_mut(bb).insts_mut().remove(&inst);
+    }
+    // each is removed after its users
+    let mut remaining = insts;
+    while !remaining.is_empty() {
+        let (unused, used): (Vec<_>, Vec<_>) = remaining
+            .into_iter()
+            .partition(|&inst| data.dfg().value(inst).used_by().is_empty());
+        assert!(!unused.is_empty(), "instructions are still used");
+        for inst in unused {
+            data.dfg_mut().remove_value(inst);
+        }
+        remaining = used;
+    }
+}
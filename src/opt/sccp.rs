@@ -0,0 +1,308 @@
+use crate::ir::Locations;
+use crate::opt::cfg::terminator;
+use crate::opt::rewrite::{remove_insts, replace_uses};
+use crate::sema::{Warning, WarningKind};
+use koopa::ir::builder_traits::*;
+use koopa::ir::values::BinaryOp;
+use koopa::ir::{BasicBlock, Function, FunctionData, Value, ValueKind};
+use koopa::opt::FunctionPass;
+use std::collections::{HashMap, HashSet};
+
+/// Folds the instructions on constants and propagates the results, including through
+/// basic block parameters and branches, with sparse conditional constant propagation.
+pub struct Sccp<'a> {
+    locations: &'a Locations,
+    /// Divisions reported already, as the pass may run again on the same function.
+    reported: HashSet<Value>,
+    pub warnings: Vec<Warning>,
+}
+
+impl<'a> Sccp<'a> {
+    pub fn new(locations: &'a Locations) -> Self {
+        Self {
+            locations,
+            reported: HashSet::new(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl FunctionPass for Sccp<'_> {
+    fn run_on(&mut self, _: Function, data: &mut FunctionData) {
+        // skip declarations
+        let Some(entry) = data.layout().entry_bb() else {
+            return;
+        };
+        let mut analysis = Analysis::new(data);
+        analysis.run(entry);
+        let Analysis { values, executable, .. } = analysis;
+
+        for inst in zero_divisors(data, &values, &executable) {
+            if let Some(&span) = self.locations.get(&inst).filter(|_| self.reported.insert(inst)) {
+                self.warnings.push(Warning { kind: WarningKind::DivisionByZero, span, note: None });
+            }
+        }
+
+        // branches on constants become jumps
+        for &bb in &executable {
+            let Some(term) = terminator(data, bb) else {
+                continue;
+            };
+            let ValueKind::Branch(branch) = data.dfg().value(term).kind() else {
+                continue;
+            };
+            let Lattice::Const(cond) = lattice(data, &values, branch.cond()) else {
+                continue;
+            };
+            let (target, args) = match cond {
+                0 => (branch.false_bb(), branch.false_args().to_vec()),
+                _ => (branch.true_bb(), branch.true_args().to_vec()),
+            };
+            data.dfg_mut().replace_value_with(term).jump_with_args(target, args);
+        }
+
+        // values known to be constant are replaced, the instructions computing them removed
+        let mut replaced = HashMap::new();
+        let mut folded = Vec::new();
+        for (&value, &lattice) in &values {
+            if let Lattice::Const(constant) = lattice {
+                replaced.insert(value, data.dfg_mut().new_value().integer(constant));
+                if data.layout().parent_bb(value).is_some() {
+                    folded.push(value);
+                }
+            }
+        }
+        replace_uses(data, &replaced);
+        remove_insts(data, folded);
+    }
+}
+
+/// What is known about a value.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Lattice {
+    /// Not computed yet.
+    Unknown,
+    Const(i32),
+    /// May take different values.
+    Varying,
+}
+
+impl Lattice {
+    /// Merges what is known from two paths.
+    fn meet(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Unknown, other) | (other, Self::Unknown) => other,
+            (Self::Const(lhs), Self::Const(rhs)) if lhs == rhs => self,
+            _ => Self::Varying,
+        }
+    }
+}
+
+/// Returns what is known about the value, constants are known anyway.
+fn lattice(data: &FunctionData, values: &HashMap<Value, Lattice>, value: Value) -> Lattice {
+    if value.is_global() {
+        return Lattice::Varying;
+    }
+    match data.dfg().value(value).kind() {
+        ValueKind::Integer(integer) => Lattice::Const(integer.value()),
+        // a branch on an undefined value may go either way
+        ValueKind::Undef(_) | ValueKind::FuncArgRef(_) => Lattice::Varying,
+        _ => values.get(&value).copied().unwrap_or(Lattice::Unknown),
+    }
+}
+
+/// Evaluates a binary operation with the wrapping semantics of RISC-V, `None` on division by zero.
+pub fn evaluate(op: BinaryOp, lhs: i32, rhs: i32) -> Option<i32> {
+    Some(match op {
+        BinaryOp::NotEq => (lhs != rhs) as i32,
+        BinaryOp::Eq => (lhs == rhs) as i32,
+        BinaryOp::Gt => (lhs > rhs) as i32,
+        BinaryOp::Lt => (lhs < rhs) as i32,
+        BinaryOp::Ge => (lhs >= rhs) as i32,
+        BinaryOp::Le => (lhs <= rhs) as i32,
+        BinaryOp::Add => lhs.wrapping_add(rhs),
+        BinaryOp::Sub => lhs.wrapping_sub(rhs),
+        BinaryOp::Mul => lhs.wrapping_mul(rhs),
+        BinaryOp::Div | BinaryOp::Mod if rhs == 0 => return None,
+        BinaryOp::Div => lhs.wrapping_div(rhs),
+        BinaryOp::Mod => lhs.wrapping_rem(rhs),
+        BinaryOp::And => lhs & rhs,
+        BinaryOp::Or => lhs | rhs,
+        BinaryOp::Xor => lhs ^ rhs,
+        BinaryOp::Shl => lhs.wrapping_shl(rhs as u32),
+        BinaryOp::Shr => (lhs as u32).wrapping_shr(rhs as u32) as i32,
+        BinaryOp::Sar => lhs.wrapping_shr(rhs as u32),
+    })
+}
+
+/// Returns the divisions and remainders in executable blocks whose divisor is known to be zero
+/// once the analysis has converged, in the order of the layout.
+fn zero_divisors(
+    data: &FunctionData,
+    values: &HashMap<Value, Lattice>,
+    executable: &HashSet<BasicBlock>,
+) -> Vec<Value> {
+    data.layout()
+        .bbs()
+        .iter()
+        .filter(|(bb, _)| executable.contains(bb))
+        .flat_map(|(_, node)| node.insts().keys().copied())
+        .filter(|&inst| match data.dfg().value(inst).kind() {
+            ValueKind::Binary(binary) => {
+                matches!(binary.op(), BinaryOp::Div | BinaryOp::Mod)
+                    && lattice(data, values, binary.rhs()) == Lattice::Const(0)
+            }
+            _ => false,
+        })
+        .collect()
+}
+
+struct Analysis<'f> {
+    data: &'f FunctionData,
+    /// What is known about the instructions and basic block parameters.
+    values: HashMap<Value, Lattice>,
+    executable: HashSet<BasicBlock>,
+    edges: HashSet<(BasicBlock, BasicBlock)>,
+    /// Targets of the edges found to be executable, which are to be visited.
+    edge_worklist: Vec<BasicBlock>,
+    /// Values whose lattice has changed, whose users are to be visited again.
+    value_worklist: Vec<Value>,
+}
+
+impl<'f> Analysis<'f> {
+    fn new(data: &'f FunctionData) -> Self {
+        Self {
+            data,
+            values: HashMap::new(),
+            executable: HashSet::new(),
+            edges: HashSet::new(),
+            edge_worklist: Vec::new(),
+            value_worklist: Vec::new(),
+        }
+    }
+
+    fn run(&mut self, entry: BasicBlock) {
+        self.visit_block(entry);
+        loop {
+            if let Some(bb) = self.edge_worklist.pop() {
+                self.visit_params(bb);
+                if !self.executable.contains(&bb) {
+                    self.visit_block(bb);
+                }
+            } else if let Some(value) = self.value_worklist.pop() {
+                for &user in self.data.dfg().value(value).used_by() {
+                    let bb = self.data.layout().parent_bb(user);
+                    if bb.is_some_and(|bb| self.executable.contains(&bb)) {
+                        self.visit_inst(user);
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn visit_block(&mut self, bb: BasicBlock) {
+        self.executable.insert(bb);
+        let insts: Vec<_> = self.data.layout().bbs().node(&bb).unwrap().insts().keys().copied().collect();
+        for inst in insts {
+            self.visit_inst(inst);
+        }
+    }
+
+    fn visit_inst(&mut self, inst: Value) {
+        let bb = self.data.layout().parent_bb(inst).unwrap();
+        match self.data.dfg().value(inst).kind() {
+            ValueKind::Binary(binary) => {
+                let lhs = lattice(self.data, &self.values, binary.lhs());
+                let rhs = lattice(self.data, &self.values, binary.rhs());
+                if matches!(binary.op(), BinaryOp::Div | BinaryOp::Mod) && rhs == Lattice::Const(0) {
+                    // left to fail at run time
+                    return self.update(inst, Lattice::Varying);
+                }
+                let value = match (lhs, rhs) {
+                    (Lattice::Const(lhs), Lattice::Const(rhs)) => {
+                        Lattice::Const(evaluate(binary.op(), lhs, rhs).unwrap())
+                    }
+                    (Lattice::Varying, _) | (_, Lattice::Varying) => Lattice::Varying,
+                    _ => Lattice::Unknown,
+                };
+                self.update(inst, value);
+            }
+            ValueKind::Branch(branch) => {
+                let (true_bb, false_bb) = (branch.true_bb(), branch.false_bb());
+                match lattice(self.data, &self.values, branch.cond()) {
+                    Lattice::Unknown => {}
+                    Lattice::Const(0) => self.mark_edge(bb, false_bb),
+                    Lattice::Const(_) => self.mark_edge(bb, true_bb),
+                    Lattice::Varying => {
+                        self.mark_edge(bb, true_bb);
+                        self.mark_edge(bb, false_bb);
+                    }
+                }
+            }
+            ValueKind::Jump(jump) => self.mark_edge(bb, jump.target()),
+            ValueKind::Store(_) | ValueKind::Return(_) => {}
+            // loads, calls and pointers are not tracked
+            _ => self.update(inst, Lattice::Varying),
+        }
+    }
+
+    /// Marks the edge executable, or passes the arguments again if it is already.
+    fn mark_edge(&mut self, from: BasicBlock, to: BasicBlock) {
+        if self.edges.insert((from, to)) {
+            self.edge_worklist.push(to);
+        } else {
+            self.visit_params(to);
+        }
+    }
+
+    /// Merges the arguments passed to the parameters of the block through executable edges.
+    fn visit_params(&mut self, bb: BasicBlock) {
+        let params = self.data.dfg().bb(bb).params();
+        if params.is_empty() {
+            return;
+        }
+        let mut merged = vec![Lattice::Unknown; params.len()];
+        for &user in self.data.dfg().bb(bb).used_by() {
+            let Some(from) = self.data.layout().parent_bb(user) else {
+                continue;
+            };
+            if !self.edges.contains(&(from, bb)) {
+                continue;
+            }
+            let args: Vec<&[Value]> = match self.data.dfg().value(user).kind() {
+                ValueKind::Jump(jump) => vec![jump.args()],
+                ValueKind::Branch(branch) => {
+                    let mut args = Vec::new();
+                    if branch.true_bb() == bb {
+                        args.push(branch.true_args());
+                    }
+                    if branch.false_bb() == bb {
+                        args.push(branch.false_args());
+                    }
+                    args
+                }
+                _ => Vec::new(),
+            };
+            for args in args {
+                for (merged, &arg) in merged.iter_mut().zip(args) {
+                    *merged = merged.meet(lattice(self.data, &self.values, arg));
+                }
+            }
+        }
+        for (&param, value) in params.iter().zip(merged) {
+            self.update(param, value);
+        }
+    }
+
+    /// Lowers what is known about the value, and visits its users again if it has changed.
+    fn update(&mut self, value: Value, lattice: Lattice) {
+        let old = self.values.get(&value).copied().unwrap_or(Lattice::Unknown);
+        let new = old.meet(lattice);
+        if new != old {
+            self.values.insert(value, new);
+            self.value_worklist.push(value);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::opt::cfg::{remove_unreachable, terminator};
+use crate::opt::rewrite::{remove_inst, replace_uses};
+use koopa::ir::builder_traits::*;
+use koopa::ir::{BasicBlock, Function, FunctionData, Value, ValueKind};
+use koopa::opt::FunctionPass;
+use std::collections::HashMap;
+
+/// Simplifies the control flow graph to a fixpoint: folds branches to the same place,
+/// threads jumps through empty blocks and merges blocks into their only predecessor.
+pub struct SimplifyCfg;
+
+impl FunctionPass for SimplifyCfg {
+    fn run_on(&mut self, _: Function, data: &mut FunctionData) {
+        // skip declarations
+        if data.layout().entry_bb().is_none() {
+            return;
+        }
+        let mut changed = true;
+        while changed {
+            changed = remove_unreachable(data);
+            let bbs: Vec<_> = data.layout().bbs().keys().copied().collect();
+            for bb in bbs {
+                // blocks may be merged away while others are visited
+                if data.layout().bbs().node(&bb).is_none() {
+                    continue;
+                }
+                changed |= fold_branch(data, bb) | thread_jumps(data, bb) | merge_into_pred(data, bb);
+            }
+        }
+    }
+}
+
+/// Turns `br %cond, %bb(args), %bb(args)` at the end of the block into `jump %bb(args)`.
+fn fold_branch(data: &mut FunctionData, bb: BasicBlock) -> bool {
+    let term = terminator(data, bb).unwrap();
+    let ValueKind::Branch(branch) = data.dfg().value(term).kind() else {
+        return false;
+    };
+    if branch.true_bb() != branch.false_bb() || branch.true_args() != branch.false_args() {
+        return false;
+    }
+    let (target, args) = (branch.true_bb(), branch.true_args().to_vec());
+    data.dfg_mut().replace_value_with(term).jump_with_args(target, args);
+    true
+}
+
+/// Returns the target and the arguments of the block if it does nothing but jump, and its
+/// parameters are only passed on by the jump rather than used in the blocks it dominates.
+fn forwarding(data: &FunctionData, bb: BasicBlock) -> Option<(BasicBlock, Vec<Value>)> {
+    let insts = data.layout().bbs().node(&bb)?.insts();
+    let term = *insts.front_key()?;
+    let params = data.dfg().bb(bb).params();
+    if params.iter().any(|&param| data.dfg().value(param).used_by().iter().any(|&user| user != term)) {
+        return None;
+    }
+    match data.dfg().value(term).kind() {
+        ValueKind::Jump(jump) if insts.len() == 1 => Some((jump.target(), jump.args().to_vec())),
+        _ => None,
+    }
+}
+
+/// Makes the predecessors of the block jump directly to where the block jumps, if it does
+/// nothing else. The block is left unreachable once all of them are redirected.
+fn thread_jumps(data: &mut FunctionData, bb: BasicBlock) -> bool {
+    let Some((target, args)) = forwarding(data, bb) else {
+        return false;
+    };
+    // targets which forward as well are threaded first, which also leaves loops of empty blocks alone
+    if target == bb || forwarding(data, target).is_some() || data.layout().entry_bb() == Some(bb) {
+        return false;
+    }
+    let params = data.dfg().bb(bb).params().to_vec();
+    let forward = |passed: &[Value]| -> Vec<Value> {
+        let passed: HashMap<_, _> = params.iter().copied().zip(passed.iter().copied()).collect();
+        args.iter().map(|arg| passed.get(arg).copied().unwrap_or(*arg)).collect()
+    };
+    let users: Vec<_> = data.dfg().bb(bb).used_by().iter().copied().collect();
+    for user in &users {
+        let kind = data.dfg().value(*user).kind().clone();
+        match kind {
+            ValueKind::Jump(jump) => {
+                let args = forward(jump.args());
+                data.dfg_mut().replace_value_with(*user).jump_with_args(target, args);
+            }
+            ValueKind::Branch(branch) => {
+                let (true_bb, true_args) = match branch.true_bb() == bb {
+                    true => (target, forward(branch.true_args())),
+                    false => (branch.true_bb(), branch.true_args().to_vec()),
+                };
+                let (false_bb, false_args) = match branch.false_bb() == bb {
+                    true => (target, forward(branch.false_args())),
+                    false => (branch.false_bb(), branch.false_args().to_vec()),
+                };
+                data.dfg_mut()
+                    .replace_value_with(*user)
+                    .branch_with_args(branch.cond(), true_bb, false_bb, true_args, false_args);
+            }
+            _ => {}
+        }
+    }
+    !users.is_empty()
+}
+
+/// Appends the block to its predecessor, if it is its only predecessor and the block is its
+/// only successor. The parameters are replaced by the arguments of the jump.
+fn merge_into_pred(data: &mut FunctionData, bb: BasicBlock) -> bool {
+    let used_by = data.dfg().bb(bb).used_by();
+    if used_by.len() != 1 {
+        return false;
+    }
+    let jump = *used_by.iter().next().unwrap();
+    let ValueKind::Jump(jump_data) = data.dfg().value(jump).kind() else {
+        return false;
+    };
+    let pred = data.layout().parent_bb(jump).unwrap();
+    if pred == bb {
+        return false;
+    }
+    let replaced: HashMap<_, _> = data
+        .dfg()
+        .bb(bb)
+        .params()
+        .iter()
+        .copied()
+        .zip(jump_data.args().iter().copied())
+        .collect();
+    remove_inst(data, jump);
+    let insts: Vec<_> = data.layout().bbs().node(&bb).unwrap().insts().keys().copied().collect();
+    for inst in insts {
+        data.layout_mut().bb_mut(bb).insts_mut().remove(&inst);
+        data.layout_mut().bb_mut(pred).insts_mut().push_key_back(inst).unwrap();
+    }
+    replace_uses(data, &replaced);
+    data.layout_mut().bbs_mut().remove(&bb);
+    data.dfg_mut().remove_bb(bb);
+    true
+}
@@ -0,0 +1,555 @@
+use crate::ir::{generate_program, Locations};
+use crate::opt::combine::Combine;
+use crate::opt::dce::Dce;
+use crate::opt::mem2reg::Mem2Reg;
+use crate::opt::optimize;
+use crate::opt::sccp::Sccp;
+use crate::opt::simplify_cfg::SimplifyCfg;
+use crate::source::Span;
+use crate::{parser, sema};
+use koopa::back::KoopaGenerator;
+use koopa::front::Driver;
+use koopa::ir::values::BinaryOp;
+use koopa::ir::{Program, ValueKind};
+use koopa::opt::FunctionPass;
+
+fn parse(koopa: &str) -> Program {
+    Driver::from(koopa).generate_program().unwrap()
+}
+
+fn print(program: &Program) -> String {
+    let mut gen = KoopaGenerator::new(Vec::new());
+    gen.generate_on(program).unwrap();
+    String::from_utf8(gen.writer()).unwrap()
+}
+
+/// Compiles the SysY source with the optimizations, returns the source text each warning is about.
+fn warnings(source: &str) -> Vec<&str> {
+    let Ok(comp_unit) = parser::parse(source) else {
+        panic!("syntax errors");
+    };
+    let Ok(analysis) = sema::analyze(&comp_unit) else {
+        panic!("semantic errors");
+    };
+    let (mut program, locations) = generate_program(&comp_unit, &analysis);
+    let warnings = optimize(&mut program, &locations);
+    warnings.iter().map(|warning| &source[warning.span.start..warning.span.end]).collect()
+}
+
+/// Runs the pass on every function of the Koopa IR text, returns the program printed back.
+fn run(pass: &mut impl FunctionPass, koopa: &str) -> String {
+    let mut program = parse(koopa);
+    let funcs: Vec<_> = program.func_layout().to_vec();
+    for func in funcs {
+        pass.run_on(func, program.func_mut(func));
+    }
+    print(&program)
+}
+
+/// Checks that the pass turns the Koopa IR text `koopa` into `expected`.
+fn check(pass: &mut impl FunctionPass, koopa: &str, expected: &str) {
+    assert_eq!(run(pass, koopa).trim(), expected.trim());
+}
+
+#[test]
+fn mem2reg_straight_line() {
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %x = alloc i32
+  store @a, %x
+  %0 = load %x
+  %1 = add %0, 1
+  store %1, %x
+  %2 = load %x
+  ret %2
+}
+";
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = add @a, 1
+  ret %0
+}
+";
+    check(&mut Mem2Reg, koopa, expected);
+}
+
+#[test]
+fn mem2reg_diamond() {
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %x = alloc i32
+  br @a, %then, %else
+
+%then:
+  store 1, %x
+  jump %end
+
+%else:
+  store 2, %x
+  jump %end
+
+%end:
+  %0 = load %x
+  ret %0
+}
+";
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  br @a, %then, %else
+
+%then:
+  jump %end(1)
+
+%else:
+  jump %end(2)
+
+%end(%x: i32):
+  ret %x
+}
+";
+    check(&mut Mem2Reg, koopa, expected);
+}
+
+#[test]
+fn mem2reg_loop() {
+    let koopa = r"
+fun @f(@n: i32): i32 {
+%entry:
+  %i = alloc i32
+  %s = alloc i32
+  store 0, %i
+  store 0, %s
+  jump %cond
+
+%cond:
+  %0 = load %i
+  %1 = lt %0, @n
+  br %1, %body, %end
+
+%body:
+  %2 = load %s
+  %3 = load %i
+  %4 = add %2, %3
+  store %4, %s
+  %5 = add %3, 1
+  store %5, %i
+  jump %cond
+
+%end:
+  %6 = load %s
+  ret %6
+}
+";
+    let expected = r"
+fun @f(@n: i32): i32 {
+%entry:
+  jump %cond(0, 0)
+
+%cond(%i: i32, %s: i32):
+  %0 = lt %i, @n
+  br %0, %body, %end
+
+%body:
+  %1 = add %s, %i
+  %2 = add %i, 1
+  jump %cond(%2, %1)
+
+%end:
+  ret %s
+}
+";
+    check(&mut Mem2Reg, koopa, expected);
+}
+
+#[test]
+fn mem2reg_keeps_arrays() {
+    let koopa = r"
+decl @getarray(*i32): i32
+
+fun @f(): i32 {
+%entry:
+  %a = alloc [i32, 2]
+  %0 = getelemptr %a, 0
+  %1 = call @getarray(%0)
+  %x = alloc i32
+  store %1, %x
+  %2 = load %x
+  ret %2
+}
+";
+    let expected = r"
+decl @getarray(*i32): i32
+
+fun @f(): i32 {
+%entry:
+  %a = alloc [i32, 2]
+  %0 = getelemptr %a, 0
+  %1 = call @getarray(%0)
+  ret %1
+}
+";
+    check(&mut Mem2Reg, koopa, expected);
+}
+
+#[test]
+fn sccp_folds_through_params_and_branches() {
+    let koopa = r"
+fun @f(): i32 {
+%entry:
+  %0 = add 1, 2
+  %1 = mul %0, 4
+  %2 = gt %1, 10
+  br %2, %then, %else
+
+%then:
+  jump %end(%1)
+
+%else:
+  jump %end(0)
+
+%end(%r: i32):
+  %3 = add %r, 1
+  ret %3
+}
+";
+    let expected = r"
+fun @f(): i32 {
+%entry:
+  jump %then
+
+%then:
+  jump %end(12)
+
+%else:
+  jump %end(0)
+
+%end(%r: i32):
+  ret 13
+}
+";
+    check(&mut Sccp::new(&Locations::new()), koopa, expected);
+}
+
+#[test]
+fn sccp_wraps() {
+    let koopa = r"
+fun @f(): i32 {
+%entry:
+  %0 = add 2147483647, 1
+  %1 = div %0, -1
+  %2 = mod %0, -1
+  %3 = sub %1, %2
+  %4 = mul 65536, 65536
+  %5 = add %3, %4
+  ret %5
+}
+";
+    let expected = r"
+fun @f(): i32 {
+%entry:
+  ret -2147483648
+}
+";
+    check(&mut Sccp::new(&Locations::new()), koopa, expected);
+}
+
+#[test]
+fn sccp_warns_about_zero_divisors() {
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = sub 1, 1
+  %1 = div @a, %0
+  jump %loop(0)
+
+%loop(%x: i32):
+  %2 = mod 10, %x
+  %3 = add %x, 1
+  %4 = lt %3, @a
+  br %4, %loop(%3), %end
+
+%end:
+  %5 = mod %1, 0
+  ret %5
+}
+";
+    let mut program = parse(koopa);
+    let func = program.func_layout()[0];
+    // each division is located at its position among them
+    let data = program.func(func);
+    let divisions = data.layout().bbs().nodes().flat_map(|node| node.insts().keys().copied()).filter(|&inst| {
+        let kind = data.dfg().value(inst).kind();
+        matches!(kind, ValueKind::Binary(binary) if matches!(binary.op(), BinaryOp::Div | BinaryOp::Mod))
+    });
+    let locations: Locations = divisions.enumerate().map(|(i, inst)| (inst, Span::new(i, i + 1))).collect();
+    let mut sccp = Sccp::new(&locations);
+    sccp.run_on(func, program.func_mut(func));
+    // `%x` is only zero on the first visit of the loop
+    let found: Vec<_> = sccp.warnings.iter().map(|warning| warning.span.start).collect();
+    assert_eq!(found, [0, 2]);
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = div @a, 0
+  jump %loop(0)
+
+%loop(%x: i32):
+  %1 = mod 10, %x
+  %2 = add %x, 1
+  %3 = lt %2, @a
+  br %3, %loop(%2), %end
+
+%end:
+  %4 = mod %0, 0
+  ret %4
+}
+";
+    assert_eq!(print(&program).trim(), expected.trim());
+}
+
+#[test]
+fn dce_removes_dead_insts() {
+    let koopa = r"
+decl @putint(i32)
+
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = add @a, 1
+  %1 = mul %0, 2
+  %2 = alloc i32
+  store @a, %2
+  %3 = load %2
+  call @putint(%3)
+  %4 = sub %3, %1
+  ret @a
+}
+";
+    let expected = r"
+decl @putint(i32)
+
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = alloc i32
+  store @a, %0
+  %1 = load %0
+  call @putint(%1)
+  ret @a
+}
+";
+    check(&mut Dce, koopa, expected);
+}
+
+#[test]
+fn dce_removes_dead_params() {
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  jump %loop(@a, 0, 1)
+
+%loop(%dead: i32, %i: i32, %step: i32):
+  %0 = add %i, %step
+  %1 = mul %dead, 3
+  %2 = lt %0, @a
+  br %2, %loop(%1, %0, %step), %end(%i)
+
+%end(%r: i32):
+  ret %r
+}
+";
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  jump %loop(0, 1)
+
+%loop(%i: i32, %step: i32):
+  %0 = add %i, %step
+  %1 = lt %0, @a
+  br %1, %loop(%0, %step), %end(%i)
+
+%end(%r: i32):
+  ret %r
+}
+";
+    check(&mut Dce, koopa, expected);
+}
+
+#[test]
+fn simplify_cfg_folds_and_threads() {
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  br @a, %empty, %other
+
+%empty:
+  jump %end(@a)
+
+%other:
+  %0 = add @a, 1
+  br %0, %empty2(%0), %empty2(%0)
+
+%empty2(%x: i32):
+  jump %end(%x)
+
+%end(%r: i32):
+  ret %r
+}
+";
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  br @a, %end(@a), %other
+
+%other:
+  %0 = add @a, 1
+  jump %end(%0)
+
+%end(%r: i32):
+  ret %r
+}
+";
+    check(&mut SimplifyCfg, koopa, expected);
+}
+
+#[test]
+fn simplify_cfg_merges_blocks() {
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = add @a, 1
+  jump %next(%0)
+
+%next(%x: i32):
+  %1 = mul %x, 2
+  jump %last
+
+%last:
+  ret %1
+}
+";
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = add @a, 1
+  %1 = mul %0, 2
+  ret %1
+}
+";
+    check(&mut SimplifyCfg, koopa, expected);
+}
+
+#[test]
+fn optimize_removes_conditions_of_folded_branches() {
+    let koopa = r"
+decl @putint(i32)
+
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = gt @a, 5
+  br %0, %then, %else
+
+%then:
+  jump %end
+
+%else:
+  jump %end
+
+%end:
+  call @putint(@a)
+  ret 0
+}
+";
+    let mut program = parse(koopa);
+    optimize(&mut program, &Locations::new());
+    let expected = r"
+decl @putint(i32)
+
+fun @f(@a: i32): i32 {
+%entry:
+  call @putint(@a)
+  ret 0
+}
+";
+    assert_eq!(print(&program).trim(), expected.trim());
+}
+
+#[test]
+fn combine_applies_identities() {
+    let koopa = r"
+fun @f(@a: i32, @b: i32): i32 {
+%entry:
+  %0 = mul @a, 1
+  %1 = add 0, %0
+  %2 = sub %1, @a
+  %3 = mul 0, @b
+  %4 = add %2, %3
+  %5 = lt @a, @b
+  %6 = eq 0, %5
+  %7 = eq 0, %6
+  %8 = ne %7, 0
+  %9 = add %4, %8
+  ret %9
+}
+";
+    // the instructions left dead are for dce to remove
+    let expected = r"
+fun @f(@a: i32, @b: i32): i32 {
+%entry:
+  %0 = lt @a, @b
+  %1 = ge @a, @b
+  %2 = lt @a, @b
+  ret %2
+}
+";
+    check(&mut Combine, koopa, expected);
+}
+
+#[test]
+fn combine_resolves_chains() {
+    // `%0` and `%2` are both combined in the same round, `%2` to what `%0` is
+    let koopa = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = add @a, 0
+  %1 = sub 0, %0
+  %2 = sub 0, %1
+  ret %2
+}
+";
+    let expected = r"
+fun @f(@a: i32): i32 {
+%entry:
+  %0 = sub 0, @a
+  ret @a
+}
+";
+    check(&mut Combine, koopa, expected);
+}
+
+#[test]
+fn optimize_warns_about_zero_divisors_in_conditions() {
+    let source = r"
+int main() {
+  int x = getint();
+  if (x / 0 > 1) x = 1;
+  while (x % 0) x = 2;
+  return x;
+}
+";
+    assert_eq!(warnings(source), ["x / 0", "x % 0"]);
+}
+
+#[test]
+fn optimize_locates_each_zero_divisor() {
+    let source = r"
+int main() {
+  int x = getint();
+  putint(1 + x / 0 + x * 2 % 0);
+  return 0;
+}
+";
+    assert_eq!(warnings(source), ["x / 0", "x * 2 % 0"]);
+}
@@ -1,3 +1,80 @@
+// generated by LALRPOP from the grammar files
+#[allow(clippy::ptr_arg)]
 pub mod sysy;
 
 pub use sysy::*;
+
+use crate::ast::CompUnit;
+use crate::source::Span;
+use lalrpop_util::ParseError;
+use lalrpop_util::lexer::Token;
+
+/// A syntax error with the range of the offending token.
+pub struct SyntaxError {
+    pub span: Span,
+    pub message: String,
+}
+
+/// Parses the SysY source, reporting every syntax error the parser recovers from.
+pub fn parse(input: &str) -> Result<CompUnit, Vec<SyntaxError>> {
+    let mut errors = Vec::new();
+    let result = CompUnitParser::new().parse(&mut errors, input);
+    let mut errors: Vec<_> = errors.into_iter().map(|recovery| recovery.error).collect();
+    match result {
+        Ok(comp_unit) if errors.is_empty() => return Ok(comp_unit),
+        Ok(_) => {}
+        Err(err) => errors.push(err),
+    }
+    Err(errors.into_iter().map(SyntaxError::from).collect())
+}
+
+impl From<ParseError<usize, Token<'_>, &'static str>> for SyntaxError {
+    fn from(err: ParseError<usize, Token<'_>, &'static str>) -> Self {
+        let (span, message) = match err {
+            ParseError::InvalidToken { location } => {
+                (Span::new(location, location + 1), "invalid token".into())
+            }
+            ParseError::UnrecognizedEof { location, expected } => {
+                (Span::new(location, location), format!("unexpected end of file{}", expected_tokens(&expected)))
+            }
+            ParseError::UnrecognizedToken { token: (l, token, r), expected } => {
+                (Span::new(l, r), format!("unexpected token `{}`{}", token.1, expected_tokens(&expected)))
+            }
+            ParseError::ExtraToken { token: (l, token, r) } => {
+                (Span::new(l, r), format!("extra token `{}`", token.1))
+            }
+            ParseError::User { error } => (Span::default(), error.into()),
+        };
+        Self { span, message }
+    }
+}
+
+/// Describes the terminals the parser expected, regex terminals are given readable names.
+fn expected_tokens(expected: &[String]) -> String {
+    let mut names: Vec<&str> = Vec::new();
+    for token in expected {
+        let name = match token.as_str() {
+            r####"r#"[_a-zA-Z][_a-zA-Z0-9]*"#"#### => "identifier",
+            token if token.starts_with("r#") => "integer literal",
+            token => token.trim_matches('"'),
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    match names.as_slice() {
+        [] => String::new(),
+        [name] => format!(", expected {}", quote(name)),
+        names => {
+            let names: Vec<_> = names.iter().map(|name| quote(name)).collect();
+            format!(", expected one of {}", names.join(", "))
+        }
+    }
+}
+
+fn quote(name: &str) -> String {
+    match name {
+        "identifier" | "integer literal" => name.into(),
+        name => format!("`{}`", name),
+    }
+}
@@ -1,6 +1,8 @@
 // auto-generated: "lalrpop 0.22.0"
-// sha3: 2c875c42277dcdc8ac9368bed0a4b583cdf6e67cd1d422857805216de6c7bc21
+// sha3: 6514a5f1ea0ee950dd7e855c29c48a1cc801e0a550d80a69b35295cc46002c3c
 use crate::ast::*;
+use crate::source::Span;
+use lalrpop_util::ErrorRecovery;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -13,6 +15,8 @@ extern crate alloc;
 mod __parse__CompUnit {
 
     use crate::ast::*;
+    use crate::source::Span;
+    use lalrpop_util::ErrorRecovery;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
@@ -24,300 +28,490 @@ mod __parse__CompUnit {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(Def),
-        Variant2(alloc::vec::Vec<Def>),
-        Variant3(Exp),
-        Variant4(alloc::vec::Vec<Exp>),
-        Variant5(FuncFParam),
-        Variant6(alloc::vec::Vec<FuncFParam>),
-        Variant7(InitVal),
-        Variant8(Option<InitVal>),
-        Variant9(Block),
-        Variant10(Option<Block>),
-        Variant11(BlockItem),
-        Variant12(alloc::vec::Vec<BlockItem>),
-        Variant13(FuncDef),
-        Variant14(alloc::vec::Vec<FuncDef>),
-        Variant15(FuncFParams),
-        Variant16(Option<FuncFParams>),
-        Variant17(FuncRParams),
-        Variant18(Option<FuncRParams>),
-        Variant19(AddExp),
-        Variant20(AddOp),
-        Variant21(BType),
-        Variant22(CompUnit),
-        Variant23(Decl),
-        Variant24(EqExp),
-        Variant25(EqOp),
-        Variant26(FuncType),
-        Variant27(String),
-        Variant28(i32),
-        Variant29(LAndExp),
-        Variant30(LOrExp),
-        Variant31(LVal),
-        Variant32(MulExp),
-        Variant33(MulOp),
-        Variant34(PrimaryExp),
-        Variant35(RelExp),
-        Variant36(RelOp),
-        Variant37(Stmt),
-        Variant38(UnaryExp),
-        Variant39(UnaryOp),
-    }
-    const __ACTION: &[i8] = &[
+        Variant1(__lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>),
+        Variant2(ConstDef),
+        Variant3(alloc::vec::Vec<ConstDef>),
+        Variant4(ConstInitVal),
+        Variant5(alloc::vec::Vec<ConstInitVal>),
+        Variant6(Def),
+        Variant7(alloc::vec::Vec<Def>),
+        Variant8(Exp),
+        Variant9(alloc::vec::Vec<Exp>),
+        Variant10(FuncFParam),
+        Variant11(alloc::vec::Vec<FuncFParam>),
+        Variant12(InitVal),
+        Variant13(alloc::vec::Vec<InitVal>),
+        Variant14(Option<InitVal>),
+        Variant15(alloc::vec::Vec<ConstExp>),
+        Variant16(Option<alloc::vec::Vec<ConstExp>>),
+        Variant17(ConstExp),
+        Variant18(Option<Exp>),
+        Variant19(FuncFParams),
+        Variant20(Option<FuncFParams>),
+        Variant21(FuncRParams),
+        Variant22(Option<FuncRParams>),
+        Variant23(GlobalItem),
+        Variant24(alloc::vec::Vec<GlobalItem>),
+        Variant25(usize),
+        Variant26(AddExp),
+        Variant27(AddOp),
+        Variant28(BType),
+        Variant29(Block),
+        Variant30(BlockItem),
+        Variant31(Vec<BlockItem>),
+        Variant32(CompUnit),
+        Variant33(ConstDecl),
+        Variant34(Decl),
+        Variant35(EqExp),
+        Variant36(EqOp),
+        Variant37(Option<Block>),
+        Variant38(FuncDef),
+        Variant39(FuncType),
+        Variant40(String),
+        Variant41(i64),
+        Variant42(LAndExp),
+        Variant43(LOrExp),
+        Variant44(LVal),
+        Variant45(Stmt),
+        Variant46(MulExp),
+        Variant47(MulOp),
+        Variant48(Number),
+        Variant49(PrimaryExp),
+        Variant50(RelExp),
+        Variant51(RelOp),
+        Variant52(UnaryExp),
+        Variant53(UnaryOp),
+    }
+    const __ACTION: &[i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -89, 0, 0, -89, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 52, 14, 65, 10, 0, 66,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 52, 14, 65, 10, 0, 69,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0,
         // State 12
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0,
         // State 16
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, -82, 0, -82, 0, -82, 0, 90, -82, 91, 0, -82, -82, -82, 0, -82, -82, -82, 0, 0, 0, 0, 0, 0, -82, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, -89, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 92, 0, -68, 0, -68, 0, 0, -68, 0, 0, -68, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, -36, 95, -36, 0, -36, 96, -36, -36, -36, 97, -36, -36, -36, 0, -36, -36, -36, 0, 0, 0, 0, 0, 0, -36, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, -50, 0, -50, 0, -50, 0, 0, -50, 0, 0, -50, 98, 99, 0, -50, 100, 101, 0, 0, 0, 0, 0, 0, -50, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0,
         // State 22
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -143, 0, -143, 0, -143, 0, 123, -143, 124, 0, -143, -143, -143, 0, -143, -143, -143, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, -143, 0,
         // State 24
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 125, 0, -122, 0, -122, 0, 0, -122, 0, 0, -122, 0, 0, 0, 126, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, -122, 0,
         // State 25
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -126, -126, -126, 42, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 43, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, -126, 0,
         // State 26
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -63, 128, -63, 0, -63, 129, -63, -63, -63, 130, -63, -63, -63, 0, -63, -63, -63, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, -63, 0,
         // State 27
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -91, 0, -91, 0, -91, 0, 0, -91, 0, 0, -91, 131, 132, 0, -91, 133, 134, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, -91, 0,
         // State 28
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 111, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 137, 0,
         // State 31
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, -80, 124, 0, -80, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0,
         // State 32
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0,
         // State 33
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, -37, 95, -37, 0, -37, 96, -37, -37, -37, 97, -37, -37, -37, 0, -37, -37, -37, 0, 0, 0, 0, 0, 0, -37, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 142, 0,
         // State 36
-        0, 0, 0, 0, 0, -51, 0, -51, 0, -51, 0, 0, -51, 0, 0, -51, 98, 99, 0, -51, 100, 101, 0, 0, 0, 0, 0, 0, -51, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 92, 0, -69, 0, -69, 0, 0, -69, 0, 0, -69, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0,
+        0, -73, -73, -73, -73, -73, 0, 0, 0, -73, 0, 0, -73, 0, -73, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, -73, 0, -73, -73, -73, 0, -73, -73, 0, -73, -73,
         // State 39
-        0, 0, 0, 0, 0, -83, 0, -83, 0, -83, 0, 90, -83, 91, 0, -83, -83, -83, 0, -83, -83, -83, 0, 0, 0, 0, 0, 0, -83, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 148, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        83, 84, 85, 50, 80, 0, 0, 0, 23, 0, 0, 81, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, 0, 0, 0, 0, 0, 0, -63, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 170, 81, 171, 0, 172, 61, 62, 0, 173, 39, 0, 174, 175,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -64, 128, -64, 0, -64, 129, -64, -64, -64, 130, -64, -64, -64, 0, -64, -64, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, -64, 0,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -92, 0, -92, 0, -92, 0, 0, -92, 0, 0, -92, 131, 132, 0, -92, 133, 134, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 125, 0, -123, 0, -123, 0, 0, -123, 0, 0, -123, 0, 0, 0, 126, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, -123, 0,
         // State 57
-        0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, -89, -89, -89, 0, -89,
+        0, 0, 0, 0, 0, 0, -144, 0, -144, 0, -144, 0, 123, -144, 124, 0, -144, -144, -144, 0, -144, -144, -144, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, -144, 0,
         // State 58
-        0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, -25, -25, -25, -25, 0, -25,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0,
         // State 59
-        0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, -43, -43, -43, 0, -43,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 61
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, -44, -44, -44, 0, -44,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0,
         // State 65
-        0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, -41, -41, -41, -41, 0, -41,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0,
         // State 66
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, -26, -26, -26, -26, 0, -26,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71,
         // State 68
-        0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, -42, -42, -42, -42, 0, -42,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72,
         // State 69
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, -49, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, 0, -72, -72, -72, 29, -72, -72, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, 0, 0, 0, 0, 0, 0, -72, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, -78, -78, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, -78, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 171, 0, 172, 0, 62, 0, 173, 39, 0, 0, 206,
         // State 73
-        0, 0, 0, 0, 0, 0, 0, 30, 0, -70, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 171, 0, 172, 0, 62, 0, 173, 39, 0, 0, 206,
         // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0,
+        0, 106, 107, 108, 83, 103, 0, 0, 0, 30, 0, 0, 104, 0, 105, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 171, 0, 172, 0, 62, 0, 173, 39, 0, 0, 206,
         // State 75
-        0, 0, 0, 0, 0, -80, -80, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, -80, 0, 0, 0, 0, 0, 0, -80, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 76
-        0, 0, 0, 0, 0, -81, -81, -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, 0, -81, -81, -81, 0, 0, 0, 0, 0, 0, -81, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, -111, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 0, 0, 0, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, 0, 0, 0, 0, 0, 0, -94, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, -112, 0, -112, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, 0, 0, -73, -73, -73, 0, -73, -73, -73, -73, -73, -73, -73, -73, -73, 0, -73, -73, -73, 0, 0, 0, 0, 0, 0, -73, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, -113, 0, -113, 0, 0, 0, 0, 0,
         // State 79
-        -100, -100, -100, -100, -100, 0, 0, 0, -100, 0, 0, -100, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, -59, 0, -59, 0, 0, 0, 0, 0,
         // State 80
-        -98, -98, -98, -98, -98, 0, 0, 0, -98, 0, 0, -98, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        -99, -99, -99, -99, -99, 0, 0, 0, -99, 0, 0, -99, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, -60, 0, -60, 0, 0, 0, 0, 0,
         // State 82
-        0, 0, 0, 0, 0, -66, -66, -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, -66, -66, 0, 0, 0, 0, 0, 0, -66, 0,
+        0, 0, 0, 0, 0, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, 0,
         // State 83
-        0, 0, 0, 0, 0, -67, -67, -67, 0, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, -67, -67, -67, 0, 0, 0, 0, 0, 0, -67, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        0, 0, 0, 0, 0, -65, -65, -65, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, -65, -65, 0, 0, 0, 0, 0, 0, -65, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -85, -85, -85, -85, -85, 0, 0, 0, -85, 0, 0, -85, 0, -85, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, -85, 0, -85, -85, -85, -85, -85, -85, 0, -85, -85,
         // State 86
-        0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, -46, -46, -46, 0, -46,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, 19, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -76, -76, -76, -76, -76, 0, 0, 0, -76, 0, 0, -76, 0, -76, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, -76, 0, -76, -76, -76, -76, -76, -76, 0, -76, -76,
         // State 89
-        -38, -38, -38, -38, -38, 0, 0, 0, -38, 0, 0, -38, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        -39, -39, -39, -39, -39, 0, 0, 0, -39, 0, 0, -39, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -86, -86, -86, -86, -86, 0, 0, 0, -86, 0, 0, -86, 0, -86, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, 0, -86, -86,
         // State 91
-        -53, -53, -53, -53, -53, 0, 0, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        -52, -52, -52, -52, -52, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 93
-        0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, -93, -93, 0, -93,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0,
         // State 94
-        -77, -77, -77, -77, -77, 0, 0, 0, -77, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        -75, -75, -75, -75, -75, 0, 0, 0, -75, 0, 0, -75, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -136, -136, -136, 0, -136, -136, -136, -136, -136, -136, -136, -136, -136, 0, -136, -136, -136, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, -136, 0,
         // State 96
-        -76, -76, -76, -76, -76, 0, 0, 0, -76, 0, 0, -76, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 44, 0, -124, 0, 0, -124, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, -124, 0,
         // State 97
-        -84, -84, -84, -84, -84, 0, 0, 0, -84, 0, 0, -84, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, -95, 0,
         // State 98
-        -86, -86, -86, -86, -86, 0, 0, 0, -86, 0, 0, -86, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -141, -141, -141, 0, -141, -141, -141, -141, -141, -141, -141, -141, -141, 0, -141, -141, -141, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, -141, 0,
         // State 99
-        -85, -85, -85, -85, -85, 0, 0, 0, -85, 0, 0, -85, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -142, -142, -142, 0, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, -142, -142, -142, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, -142, 0,
         // State 100
-        -87, -87, -87, -87, -87, 0, 0, 0, -87, 0, 0, -87, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -162, -162, -162, 0, -162, -162, -162, -162, -162, -162, -162, -162, -162, 0, -162, -162, -162, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -162, -162, 0,
         // State 101
-        0, 0, 0, 0, 0, -97, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, 0, 0, 0, 0, 0, 0, -97, 0,
+        0, 0, 0, 0, 0, 0, -131, -131, -131, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0, -131, -131, -131, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, 0,
         // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -168, -168, -168, -168, -168, 0, 0, 0, -168, 0, 0, -168, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -166, -166, -166, -166, -166, 0, 0, 0, -166, 0, 0, -166, 0, -166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 104
-        0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, -47, -47, -47, 0, -47,
+        0, -167, -167, -167, -167, -167, 0, 0, 0, -167, 0, 0, -167, 0, -167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -120, -120, -120, 0, -120, -120, -120, -120, -120, -120, -120, -120, -120, 0, -120, -120, -120, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, -120, 0,
         // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -121, -121, -121, 0, -121, -121, -121, -121, -121, -121, -121, -121, -121, 0, -121, -121, -121, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, -121, 0,
         // State 107
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -119, -119, -119, 0, -119, -119, -119, -119, -119, -119, -119, -119, -119, 0, -119, -119, -119, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, 0,
         // State 108
-        0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, -88, -88, -88, -88, 0, -88,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, 0, 0, 0, 0, -96, -96, -96, 0, -96, -96, -96, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, 0, 0, 0, 0, 0, 0, -96, 0,
+        0, -77, -77, -77, -77, -77, 0, 0, 0, -77, 0, 0, -77, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, -77, 0, -77, -77, -77, -77, -77, -77, 0, -77, -77,
         // State 111
-        0, 0, 0, 0, 0, 0, 0, 30, 0, -71, 0, 0, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 112
-        0, 0, 0, 0, 0, -74, -74, -74, 0, -74, -74, -74, -74, -74, -74, -74, -74, -74, 0, -74, -74, -74, 0, 0, 0, 0, 0, 0, -74, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0,
         // State 113
-        0, 0, 0, 0, 0, -79, -79, -79, 0, -79, -79, -79, -79, -79, -79, -79, -79, -79, 0, -79, -79, -79, 0, 0, 0, 0, 0, 0, -79, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 115
-        0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, -91, -91, -91, -91, -91, 0, -91,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 116
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 117
-        0, 0, 0, 0, 0, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, 0, 0, 0, 0, 0, 0, -95, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, -92, -92, 0, -92,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, -96, 0, -96, 0, 0, 0, 0, 0,
         // State 119
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, -100, 0, -100, 0, 0, 0, 0, 0,
         // State 120
-        0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, -90, -90, -90, 0, -90,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, -97, 0, -97, 0, 0, 0, 0, 0,
         // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, -65, -65, -65, -65, -65, 0, 0, 0, -65, 0, 0, -65, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, -66, -66, -66, -66, -66, 0, 0, 0, -66, 0, 0, -66, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, -94, -94, -94, -94, -94, 0, 0, 0, -94, 0, 0, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, -93, -93, -93, -93, -93, 0, 0, 0, -93, 0, 0, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 55, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, -127, 0,
+        // State 127
+        0, -135, -135, -135, -135, -135, 0, 0, 0, -135, 0, 0, -135, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, -133, -133, -133, -133, -133, 0, 0, 0, -133, 0, 0, -133, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, -134, -134, -134, -134, -134, 0, 0, 0, -134, 0, 0, -134, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, -145, -145, -145, -145, -145, 0, 0, 0, -145, 0, 0, -145, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, -147, -147, -147, -147, -147, 0, 0, 0, -147, 0, 0, -147, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, -146, -146, -146, -146, -146, 0, 0, 0, -146, 0, 0, -146, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, -148, -148, -148, -148, -148, 0, 0, 0, -148, 0, 0, -148, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, -165, -165, -165, 0, -165, -165, -165, -165, -165, -165, -165, -165, -165, 0, -165, -165, -165, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, -165, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, -42, 0, 0, -42, 0, 0, -42, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, -101, 0, -101, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, -43, 0, 0, -43, 0, 0, -43, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, -98, 0, -98, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, -164, -164, -164, 0, -164, -164, -164, -164, -164, -164, -164, -164, -164, 0, -164, -164, -164, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, -164, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 44, 0, -125, 0, 0, -125, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, -125, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, -132, -132, -132, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0, -132, -132, -132, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, -140, -140, -140, 0, -140, -140, -140, -140, -140, -140, -140, -140, -140, 0, -140, -140, -140, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, -140, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, -99, 0, -99, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, -152, -152, -152, -152, -152, 0, 0, 0, -152, 0, 0, -152, 0, -152, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, -152, -152, -152, -152, -152, -152, -152, 0, -152, -152, 0, -152, -152,
+        // State 159
+        0, -74, -74, -74, -74, -74, 0, 0, 0, -74, 0, 0, -74, 0, -74, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, -74, 0, -74, -74, -74, 0, -74, -74, 0, -74, -74,
+        // State 160
+        0, -70, -70, -70, -70, -70, 0, 0, 0, -70, 0, 0, -70, 0, -70, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, 0, -70, -70, -70, 0, -70, -70, 0, -70, -70,
+        // State 161
+        0, -71, -71, -71, -71, -71, 0, 0, 0, -71, 0, 0, -71, 0, -71, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, -71, -71, 0, -71, -71, -71, 0, -71, -71, 0, -71, -71,
+        // State 162
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, -141, -141, -141, 0, 0, -141, -141, 0, -141, -141, -141, -141, -141, 67, -141, -141, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0,
+        // State 164
+        0, -160, -160, -160, -160, -160, 0, 0, 0, -160, 0, 0, -160, 0, -160, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, -160, -160, -160, 0, -160, -160, -160, 0, -160, -160, 0, -160, -160,
+        // State 165
+        0, -161, -161, -161, -161, -161, 0, 0, 0, -161, 0, 0, -161, 0, -161, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, -161, -161, -161, 0, -161, -161, -161, 0, -161, -161, 0, -161, -161,
+        // State 166
+        0, -128, -128, -128, -128, -128, 0, 0, 0, -128, 0, 0, -128, 0, -128, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, -128, -128, -128, -128, -128, 0, -128, -128, 0, -128, -128,
+        // State 167
+        0, -72, -72, -72, -72, -72, 0, 0, 0, -72, 0, 0, -72, 0, -72, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, -72, -72, -72, 0, -72, -72, -72, 0, -72, -72, 0, -72, -72,
+        // State 168
+        0, -151, -151, -151, -151, -151, 0, 0, 0, -151, 0, 0, -151, 0, -151, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, -151, -151, -151, -151, -151, -151, -151, 0, -151, -151, 0, -151, -151,
+        // State 169
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, -68, -68, -68, -68, -68, 0, 0, 0, -68, 0, 0, -68, 0, -68, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 190, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 0, 0, 0, -163, -163, -163, 0, -163, -163, -163, -163, -163, -163, -163, -163, -163, 0, -163, -163, -163, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -163, -163, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0,
+        // State 183
+        0, -150, -150, -150, -150, -150, 0, 0, 0, -150, 0, 0, -150, 0, -150, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, -150, -150, -150, -150, -150, -150, -150, 0, -150, -150, 0, -150, -150,
+        // State 184
+        0, -153, -153, -153, -153, -153, 0, 0, 0, -153, 0, 0, -153, 0, -153, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, -153, -153, -153, -153, -153, -153, -153, 0, -153, -153, 0, -153, -153,
+        // State 185
+        0, -154, -154, -154, -154, -154, 0, 0, 0, -154, 0, 0, -154, 0, -154, 0, -154, 0, 0, 0, 0, 0, 0, 0, 0, -154, -154, -154, -154, -154, -154, -154, 0, -154, -154, 0, -154, -154,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, -156, -156, -156, -156, -156, 0, 0, 0, -156, 0, 0, -156, 0, -156, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, -156, -156, -156, -156, -156, -156, -156, 0, -156, -156, 0, -156, -156,
+        // State 188
+        0, -157, -157, -157, -157, -157, 0, 0, 0, -157, 0, 0, -157, 0, -157, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, -157, -157, -157, -157, -157, -157, -157, 0, -157, -157, 0, -157, -157,
+        // State 189
+        0, -69, -69, -69, -69, -69, 0, 0, 0, -69, 0, 0, -69, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, -48, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, -155, -155, -155, -155, -155, 0, 0, 0, -155, 0, 0, -155, 0, -155, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, -155, -155, -155, -155, -155, -155, -155, 0, -155, -155, 0, -155, -155,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, -149, -149, -149, -149, -149, 0, 0, 0, -149, 0, 0, -149, 0, -149, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, -149, -149, -149, -149, -149, -149, -149, 0, -149, -149, 0, -149, -149,
+        // State 201
+        0, -158, -158, -158, -158, -158, 0, 0, 0, -158, 0, 0, -158, 0, -158, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, -158, -158, -158, -158, -158, -158, -158, 0, -158, -158, 0, -158, -158,
+        // State 202
+        0, -159, -159, -159, -159, -159, 0, 0, 0, -159, 0, 0, -159, 0, -159, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, -159, -159, -159, -159, -159, -159, -159, 0, -159, -159, 0, -159, -159,
+        // State 203
+        0, -160, -160, -160, -160, -160, 0, 0, 0, -160, 0, 0, -160, 0, -160, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, -160, -160, -160, 75, -160, -160, -160, 0, -160, -160, 0, -160, -160,
+        // State 204
+        0, -137, -137, -137, -137, -137, 0, 0, 0, -137, 0, 0, -137, 0, -137, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, -137, -137, -137, 0, -137, -137, -137, 0, -137, -137, 0, -137, -137,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 206
+        0, -130, -130, -130, -130, -130, 0, 0, 0, -130, 0, 0, -130, 0, -130, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, -130, -130, -130, -130, -130, 0, -130, -130, 0, -130, -130,
+        // State 207
+        0, -139, -139, -139, -139, -139, 0, 0, 0, -139, 0, 0, -139, 0, -139, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, -139, -139, -139, 0, -139, -139, -139, 0, -139, -139, 0, -139, -139,
+        // State 208
+        0, -129, -129, -129, -129, -129, 0, 0, 0, -129, 0, 0, -129, 0, -129, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, -129, -129, -129, -129, -129, -129, -129, 0, -129, -129, 0, -129, -129,
+        // State 209
+        0, -138, -138, -138, -138, -138, 0, 0, 0, -138, 0, 0, -138, 0, -138, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, -138, -138, -138, 0, -138, -138, -138, 0, -138, -138, 0, -138, -138,
     ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 30 + integer]
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 38 + integer]
     }
-    const __EOF_ACTION: &[i8] = &[
+    const __EOF_ACTION: &[i16] = &[
         // State 0
         0,
         // State 1
-        -45,
+        -75,
         // State 2
         0,
         // State 3
@@ -403,13 +597,13 @@ mod __parse__CompUnit {
         // State 43
         0,
         // State 44
-        -101,
+        0,
         // State 45
-        -28,
+        0,
         // State 46
         0,
         // State 47
-        -29,
+        0,
         // State 48
         0,
         // State 49
@@ -423,11 +617,11 @@ mod __parse__CompUnit {
         // State 53
         0,
         // State 54
-        -56,
+        0,
         // State 55
         0,
         // State 56
-        -55,
+        0,
         // State 57
         0,
         // State 58
@@ -445,13 +639,13 @@ mod __parse__CompUnit {
         // State 64
         0,
         // State 65
-        -41,
+        0,
         // State 66
         0,
         // State 67
         0,
         // State 68
-        -42,
+        0,
         // State 69
         0,
         // State 70
@@ -465,19 +659,19 @@ mod __parse__CompUnit {
         // State 74
         0,
         // State 75
-        0,
+        -169,
         // State 76
-        0,
+        -111,
         // State 77
-        0,
+        -112,
         // State 78
-        0,
+        -113,
         // State 79
-        0,
+        -59,
         // State 80
         0,
         // State 81
-        0,
+        -60,
         // State 82
         0,
         // State 83
@@ -485,17 +679,17 @@ mod __parse__CompUnit {
         // State 84
         0,
         // State 85
-        0,
+        -85,
         // State 86
         0,
         // State 87
         0,
         // State 88
-        0,
+        -76,
         // State 89
         0,
         // State 90
-        0,
+        -86,
         // State 91
         0,
         // State 92
@@ -535,7 +729,7 @@ mod __parse__CompUnit {
         // State 109
         0,
         // State 110
-        0,
+        -77,
         // State 111
         0,
         // State 112
@@ -551,118 +745,354 @@ mod __parse__CompUnit {
         // State 117
         0,
         // State 118
-        0,
+        -96,
         // State 119
-        0,
+        -100,
         // State 120
-        0,
+        -97,
         // State 121
         0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        -101,
+        // State 139
+        0,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        -98,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        -99,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        -68,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        0,
+        // State 177
+        0,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        -69,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        0,
+        // State 208
+        0,
+        // State 209
+        0,
     ];
-    fn __goto(state: i8, nt: usize) -> i8 {
+    fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 85,
-            5 => 116,
-            8 => 53,
-            15 => 11,
-            17 => 1,
-            22 => match state {
-                32 => 39,
-                _ => 17,
-            },
-            23 => 26,
+            2 => 87,
+            5 => 155,
+            8 => 84,
+            11 => 176,
+            14 => 117,
+            17 => 152,
             24 => match state {
-                9 | 11 => 12,
-                _ => 4,
+                8 => 89,
+                62 => 190,
+                _ => 86,
+            },
+            27 => 126,
+            35 => 1,
+            38 => match state {
+                12 | 15 | 19 | 34..=35 | 59 | 65 => 31,
+                46 => 57,
+                _ => 23,
             },
-            25 => match state {
-                6 => 54,
-                8 => 56,
-                34 => 115,
-                40 => 118,
-                42 => 120,
-                _ => 57,
+            39 => 39,
+            41 => match state {
+                51 | 72..=74 => 158,
+                70 => 201,
+                71 => 202,
+                _ => 118,
             },
-            26 => match state {
-                11 => 67,
-                _ => 58,
+            42 => 159,
+            43 => 51,
+            44 => 75,
+            45 => match state {
+                51 => 160,
+                _ => 76,
             },
-            27 => 44,
-            28 => 59,
-            29 => match state {
-                24 => 105,
-                33 => 114,
-                _ => 14,
+            46 => match state {
+                14 => 111,
+                33 => 139,
+                _ => 7,
             },
-            30 => match state {
-                29 => 38,
-                _ => 18,
+            47 => match state {
+                12 => 108,
+                19 => 116,
+                _ => 112,
             },
-            31 => 27,
-            32 => match state {
-                13 => 70,
-                15 => 87,
-                16 => 88,
-                22 => 102,
-                23 => 103,
-                25 => 106,
-                41 => 119,
-                43 => 121,
-                _ => 37,
+            48 => match state {
+                15 => 113,
+                34 => 140,
+                59 => 182,
+                65 => 194,
+                _ => 49,
             },
-            33 => match state {
-                1 => 47,
-                _ => 45,
+            49 => match state {
+                51 => 161,
+                _ => 77,
             },
-            34 => match state {
-                7 => 55,
-                10 => 66,
+            50 => match state {
+                9 => 91,
+                16 => 114,
                 _ => 5,
             },
-            35 => 50,
-            36 => 109,
-            37 => 2,
-            38 => match state {
-                2 => 48,
-                4 => 52,
-                9 | 11 => 60,
-                12 | 24 | 33 => 69,
-                _ => 71,
-            },
-            39 => 107,
-            40 => 72,
-            41 => match state {
-                30 => 111,
-                _ => 73,
+            51 => match state {
+                43 => 56,
+                _ => 24,
             },
-            42 => 74,
-            43 => match state {
-                9 | 11 => 61,
-                _ => 75,
+            52 => 40,
+            53 => match state {
+                41 => 55,
+                29 => 135,
+                42 => 148,
+                51 | 72..=74 => 162,
+                54 => 175,
+                61 => 186,
+                63 => 192,
+                66 => 195,
+                67 => 196,
+                68 => 198,
+                69 => 199,
+                _ => 93,
             },
-            44 => match state {
-                26 => 35,
-                _ => 19,
+            54 => match state {
+                32 => 138,
+                37 => 144,
+                48 => 154,
+                _ => 119,
             },
-            45 => 31,
-            46 => 76,
-            47 => 77,
-            48 => match state {
-                27 => 36,
+            55 => 78,
+            56 => match state {
+                36 => 143,
+                50 => 157,
                 _ => 20,
             },
-            49 => 32,
-            50 => 62,
-            51 => match state {
-                21 => 101,
-                31 => 112,
-                _ => 78,
+            57 => match state {
+                13 => 109,
+                _ => 92,
+            },
+            58 => 146,
+            60 => match state {
+                1 => 81,
+                _ => 79,
+            },
+            61 => match state {
+                2 => 6,
+                4 | 14 | 33 => 8,
+                9 | 16 | 60 => 17,
+                3 => 83,
+                22 => 121,
+                _ => 25,
+            },
+            62 => match state {
+                11 => 94,
+                18 => 115,
+                58 => 180,
+                64 => 193,
+                _ => 47,
+            },
+            63 => 95,
+            64 => match state {
+                44 => 149,
+                _ => 96,
             },
-            52 => 21,
+            65 => 97,
+            66 => match state {
+                51 | 72..=74 => 163,
+                _ => 98,
+            },
+            67 => match state {
+                72 => 203,
+                73 => 206,
+                74 => 208,
+                _ => 164,
+            },
+            68 => match state {
+                39 => 52,
+                _ => 26,
+            },
+            69 => 45,
+            70 => 99,
+            71 => match state {
+                73 => 207,
+                74 => 209,
+                _ => 165,
+            },
+            72 => 100,
+            73 => match state {
+                40 => 53,
+                _ => 27,
+            },
+            74 => 46,
+            75 => 166,
+            76 => match state {
+                72 => 204,
+                _ => 167,
+            },
+            77 => match state {
+                28 => 134,
+                45 => 150,
+                _ => 101,
+            },
+            78 => 28,
             _ => 0,
         }
     }
     const __TERMINAL: &[&str] = &[
+        r###"INVALID"###,
         r###"r#"0[0-7]*"#"###,
         r###"r#"0[xX][0-9a-fA-F]+"#"###,
         r###"r#"[1-9][0-9]*"#"###,
@@ -685,16 +1115,22 @@ mod __parse__CompUnit {
         r###""==""###,
         r###"">""###,
         r###"">=""###,
+        r###""[""###,
+        r###""]""###,
+        r###""break""###,
+        r###""const""###,
+        r###""continue""###,
         r###""else""###,
         r###""if""###,
         r###""int""###,
         r###""return""###,
+        r###""void""###,
         r###""while""###,
         r###""{""###,
         r###""||""###,
         r###""}""###,
     ];
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
             if next_state == 0 {
@@ -706,27 +1142,32 @@ mod __parse__CompUnit {
     }
     fn __expected_tokens_from_states<
         'input,
+        'err,
     >(
-        __states: &[i8],
-        _: core::marker::PhantomData<(&'input ())>,
+        __states: &[i16],
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> alloc::vec::Vec<alloc::string::String>
+    where
+        'input: 'err,
+        'static: 'err,
     {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&(), &())>) {
                 Some(alloc::string::ToString::to_string(terminal))
             } else {
                 None
             }
         }).collect()
     }
-    struct __StateMachine<'input>
-    where 
+    struct __StateMachine<'input, 'err>
+    where 'input: 'err, 'static: 'err
     {
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        __phantom: core::marker::PhantomData<(&'input (), &'err ())>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    impl<'input, 'err> __state_machine::ParserDefinition for __StateMachine<'input, 'err>
+    where 'input: 'err, 'static: 'err
     {
         type Location = usize;
         type Error = &'static str;
@@ -734,9 +1175,9 @@ mod __parse__CompUnit {
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
         type Success = CompUnit;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
         type NonterminalIndex = usize;
 
         #[inline]
@@ -751,44 +1192,44 @@ mod __parse__CompUnit {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
+        fn action(&self, state: i16, integer: usize) -> i16 {
             __action(state, integer)
         }
 
         #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 30 - 1)
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 38 - 1)
         }
 
         #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
+        fn eof_action(&self, state: i16) -> i16 {
             __EOF_ACTION[state as usize]
         }
 
         #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
+        fn goto(&self, state: i16, nt: usize) -> i16 {
             __goto(state, nt)
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
         }
 
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens(state)
         }
 
-        fn expected_tokens_from_states(&self, states: &[i8]) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
+        fn expected_tokens_from_states(&self, states: &[i16]) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens_from_states(states, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
         fn uses_error_recovery(&self) -> bool {
-            false
+            true
         }
 
         #[inline]
@@ -796,44 +1237,46 @@ mod __parse__CompUnit {
             &self,
             recovery: __state_machine::ErrorRecovery<Self>,
         ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
+            __Symbol::Variant1(recovery)
         }
 
         fn reduce(
             &mut self,
-            action: i8,
+            action: i16,
             start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
+            states: &mut alloc::vec::Vec<i16>,
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
+                self.errors,
                 self.input,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<(&(), &())>,
             )
         }
 
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
-            __simulate_reduce(action, core::marker::PhantomData::<(&())>)
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
         }
     }
     fn __token_to_integer<
         'input,
+        'err,
     >(
         __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> Option<usize>
     {
         #[warn(unused_variables)]
         match __token {
-            Token(2, _) if true => Some(0),
+            Token(0, _) if true => Some(0),
             Token(3, _) if true => Some(1),
             Token(4, _) if true => Some(2),
             Token(5, _) if true => Some(3),
-            Token(7, _) if true => Some(4),
+            Token(6, _) if true => Some(4),
             Token(8, _) if true => Some(5),
             Token(9, _) if true => Some(6),
             Token(10, _) if true => Some(7),
@@ -859,20 +1302,28 @@ mod __parse__CompUnit {
             Token(30, _) if true => Some(27),
             Token(31, _) if true => Some(28),
             Token(32, _) if true => Some(29),
+            Token(33, _) if true => Some(30),
+            Token(34, _) if true => Some(31),
+            Token(35, _) if true => Some(32),
+            Token(36, _) if true => Some(33),
+            Token(37, _) if true => Some(34),
+            Token(38, _) if true => Some(35),
+            Token(39, _) if true => Some(36),
             _ => None,
         }
     }
     fn __token_to_symbol<
         'input,
+        'err,
     >(
         __token_index: usize,
         __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> __Symbol<'input>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 => match __token {
+                Token(0, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -880,10 +1331,14 @@ mod __parse__CompUnit {
     }
     fn __simulate_reduce<
         'input,
-    >(
-        __reduce_index: i8,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __state_machine::SimulatedReduce<__StateMachine<'input>>
+        'err,
+    >(
+        __reduce_index: i16,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'err>>
+    where
+        'input: 'err,
+        'static: 'err,
     {
         match __reduce_index {
             0 => {
@@ -984,13 +1439,13 @@ mod __parse__CompUnit {
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 0,
                     nonterminal_produced: 10,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 10,
                 }
             }
@@ -1002,38 +1457,38 @@ mod __parse__CompUnit {
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 12,
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 12,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 13,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 14,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 14,
                 }
             }
             25 => {
@@ -1044,14 +1499,14 @@ mod __parse__CompUnit {
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 16,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 16,
                 }
             }
             28 => {
@@ -1062,140 +1517,140 @@ mod __parse__CompUnit {
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 3,
+                    nonterminal_produced: 17,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    states_to_pop: 2,
+                    nonterminal_produced: 18,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 19,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 0,
+                    nonterminal_produced: 19,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 2,
+                    nonterminal_produced: 20,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 21,
+                    states_to_pop: 3,
+                    nonterminal_produced: 20,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 2,
+                    nonterminal_produced: 21,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 21,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    states_to_pop: 0,
+                    nonterminal_produced: 21,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    states_to_pop: 3,
+                    nonterminal_produced: 22,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    states_to_pop: 0,
+                    nonterminal_produced: 23,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 25,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 25,
+                    nonterminal_produced: 24,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 4,
+                    nonterminal_produced: 24,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 3,
+                    nonterminal_produced: 25,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    states_to_pop: 0,
+                    nonterminal_produced: 26,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 28,
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 28,
+                    states_to_pop: 3,
+                    nonterminal_produced: 27,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 29,
+                    states_to_pop: 4,
+                    nonterminal_produced: 27,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 28,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 29,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    states_to_pop: 0,
+                    nonterminal_produced: 29,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 31,
+                    nonterminal_produced: 30,
                 }
             }
             52 => {
@@ -1206,55 +1661,55 @@ mod __parse__CompUnit {
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 32,
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 33,
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 1,
                     nonterminal_produced: 33,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 34,
+                    states_to_pop: 0,
+                    nonterminal_produced: 33,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 34,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 35,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 36,
+                    states_to_pop: 2,
+                    nonterminal_produced: 35,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 0,
                     nonterminal_produced: 36,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 37,
                 }
             }
@@ -1266,20 +1721,20 @@ mod __parse__CompUnit {
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 39,
+                    states_to_pop: 3,
+                    nonterminal_produced: 38,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    nonterminal_produced: 39,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    nonterminal_produced: 39,
                 }
             }
             66 => {
@@ -1290,13 +1745,13 @@ mod __parse__CompUnit {
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 41,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 4,
                     nonterminal_produced: 41,
                 }
             }
@@ -1308,56 +1763,56 @@ mod __parse__CompUnit {
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 42,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    nonterminal_produced: 42,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 44,
+                    states_to_pop: 0,
+                    nonterminal_produced: 43,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 44,
+                    states_to_pop: 2,
+                    nonterminal_produced: 43,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 45,
+                    nonterminal_produced: 44,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 4,
                     nonterminal_produced: 45,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 5,
                     nonterminal_produced: 45,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 46,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 47,
+                    states_to_pop: 4,
+                    nonterminal_produced: 46,
                 }
             }
             79 => {
@@ -1369,12 +1824,12 @@ mod __parse__CompUnit {
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 47,
+                    nonterminal_produced: 48,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 48,
                 }
             }
@@ -1386,26 +1841,26 @@ mod __parse__CompUnit {
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    states_to_pop: 4,
+                    nonterminal_produced: 48,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 49,
                 }
             }
             85 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 4,
                     nonterminal_produced: 49,
                 }
             }
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    states_to_pop: 3,
+                    nonterminal_produced: 50,
                 }
             }
             87 => {
@@ -1422,458 +1877,1079 @@ mod __parse__CompUnit {
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
+                    states_to_pop: 2,
                     nonterminal_produced: 50,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 50,
+                    states_to_pop: 1,
+                    nonterminal_produced: 51,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 50,
+                    states_to_pop: 3,
+                    nonterminal_produced: 51,
                 }
             }
             92 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 50,
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
                 }
             }
             93 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 51,
+                    nonterminal_produced: 52,
                 }
             }
             94 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 51,
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
                 }
             }
             95 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 51,
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
                 }
             }
             96 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 51,
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
                 }
             }
             97 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    states_to_pop: 6,
+                    nonterminal_produced: 55,
                 }
             }
             98 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    states_to_pop: 6,
+                    nonterminal_produced: 55,
                 }
             }
             99 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    states_to_pop: 5,
+                    nonterminal_produced: 55,
                 }
             }
-            100 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {}", __reduce_index)
-        }
-    }
-    pub struct CompUnitParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
-    }
-
-    impl Default for CompUnitParser { fn default() -> Self { Self::new() } }
-    impl CompUnitParser {
-        pub fn new() -> CompUnitParser {
-            let __builder = super::__intern_token::new_builder();
-            CompUnitParser {
-                builder: __builder,
-                _priv: (),
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 55,
+                }
             }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<CompUnit, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
-    }
-    fn __accepts<
-        'input,
-    >(
-        __error_state: Option<i8>,
-        __states: &[i8],
-        __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> bool
-    {
-        let mut __states = __states.to_vec();
-        __states.extend(__error_state);
-        loop {
-            let mut __states_len = __states.len();
-            let __top = __states[__states_len - 1];
-            let __action = match __opt_integer {
-                None => __EOF_ACTION[__top as usize],
-                Some(__integer) => __action(__top, __integer),
-            };
-            if __action == 0 { return false; }
-            if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&())>) {
+            101 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop, nonterminal_produced
-                } => (states_to_pop, nonterminal_produced),
-                __state_machine::SimulatedReduce::Accept => return true,
-            };
-            __states_len -= __to_pop;
-            __states.truncate(__states_len);
-            let __top = __states[__states_len - 1];
-            let __next_state = __goto(__top, __nt);
-            __states.push(__next_state);
-        }
-    }
-    fn __reduce<
-        'input,
-    >(
-        input: &'input str,
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<CompUnit,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
-    {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                    states_to_pop: 4,
+                    nonterminal_produced: 56,
+                }
             }
-            1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 56,
+                }
             }
-            2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 56,
+                }
             }
-            3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
-            4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 57,
+                }
             }
-            5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
             }
-            6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 58,
+                }
             }
-            7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
             }
-            8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
             }
-            9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
             }
-            10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
             }
-            11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
             }
-            12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
             }
-            13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
             }
-            14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 62,
+                }
             }
-            15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
             }
-            16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 62,
+                }
             }
-            17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
             }
-            18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
             }
-            19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
             }
-            20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
             }
-            21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 64,
+                }
             }
-            22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
+                }
             }
-            23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 65,
+                }
             }
-            24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
+                }
             }
-            25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 66,
+                }
             }
-            26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
+                }
             }
-            27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 67,
+                }
             }
-            28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 67,
+                }
             }
-            29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
             }
-            30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 68,
+                }
             }
-            31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 69,
+                }
             }
-            32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 69,
+                }
             }
-            33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 69,
+                }
             }
-            34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 70,
+                }
             }
-            35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 71,
+                }
             }
-            36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 71,
+                }
             }
-            37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 71,
+                }
             }
-            38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 72,
+                }
             }
-            39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
+                }
             }
-            40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
+                }
             }
-            41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 73,
+                }
             }
-            42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 73,
+                }
             }
-            43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 74,
+                }
             }
-            44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 74,
+                }
             }
-            45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 74,
+                }
             }
-            46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 74,
+                }
             }
-            47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 75,
+                }
             }
-            48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            149 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 75,
+                }
             }
-            49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            150 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 75,
+                }
             }
-            50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            151 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 75,
+                }
             }
-            51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            152 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 75,
+                }
             }
-            52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            153 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 75,
+                }
             }
-            53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            154 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 75,
+                }
             }
-            54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            155 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 75,
+                }
             }
-            55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            156 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 75,
+                }
             }
-            56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            157 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 75,
+                }
             }
-            57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            158 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 75,
+                }
             }
-            58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            159 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 76,
+                }
             }
-            59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            160 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 76,
+                }
             }
-            60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            161 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 77,
+                }
             }
-            61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            162 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 77,
+                }
             }
-            62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            163 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 77,
+                }
             }
-            63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            164 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 77,
+                }
             }
-            64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            165 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 78,
+                }
             }
-            65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            166 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 78,
+                }
             }
-            66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            167 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 78,
+                }
             }
-            67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            168 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct CompUnitParser {
+        builder: __lalrpop_util::lexer::MatcherBuilder,
+        _priv: (),
+    }
+
+    impl Default for CompUnitParser { fn default() -> Self { Self::new() } }
+    impl CompUnitParser {
+        pub fn new() -> CompUnitParser {
+            let __builder = super::__intern_token::new_builder();
+            CompUnitParser {
+                builder: __builder,
+                _priv: (),
             }
-            68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+            'err,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+            input: &'input str,
+        ) -> Result<CompUnit, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = self.builder.matcher(input);
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    input,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'input,
+        'err,
+    >(
+        __error_state: Option<i16>,
+        __states: &[i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> bool
+    where
+        'input: 'err,
+        'static: 'err,
+    {
+        let mut __states = __states.to_vec();
+        __states.extend(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    fn __reduce<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<Result<CompUnit,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
-            69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            1 => {
+                __reduce1(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
-            70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            2 => {
+                __reduce2(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
-            71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            3 => {
+                __reduce3(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            4 => {
+                __reduce4(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            5 => {
+                __reduce5(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            6 => {
+                __reduce6(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            7 => {
+                __reduce7(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            8 => {
+                __reduce8(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            9 => {
+                __reduce9(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            10 => {
+                __reduce10(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            11 => {
+                __reduce11(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            12 => {
+                __reduce12(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            13 => {
+                __reduce13(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            14 => {
+                __reduce14(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            15 => {
+                __reduce15(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            16 => {
+                __reduce16(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            17 => {
+                __reduce17(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            18 => {
+                __reduce18(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            19 => {
+                __reduce19(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            20 => {
+                __reduce20(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            21 => {
+                __reduce21(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            22 => {
+                __reduce22(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            23 => {
+                __reduce23(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            24 => {
+                __reduce24(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
+                __reduce30(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            31 => {
+                __reduce31(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            32 => {
+                __reduce32(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            33 => {
+                __reduce33(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            34 => {
+                __reduce34(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            35 => {
+                __reduce35(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            36 => {
+                __reduce36(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            37 => {
+                __reduce37(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            38 => {
+                __reduce38(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            39 => {
+                __reduce39(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            40 => {
+                __reduce40(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            41 => {
+                __reduce41(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            42 => {
+                __reduce42(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            43 => {
+                __reduce43(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            44 => {
+                __reduce44(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            45 => {
+                __reduce45(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            46 => {
+                __reduce46(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            47 => {
+                __reduce47(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            48 => {
+                __reduce48(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            49 => {
+                __reduce49(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            50 => {
+                __reduce50(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            51 => {
+                __reduce51(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            52 => {
+                __reduce52(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            53 => {
+                __reduce53(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            54 => {
+                __reduce54(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            55 => {
+                __reduce55(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            56 => {
+                __reduce56(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            57 => {
+                __reduce57(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            58 => {
+                __reduce58(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            59 => {
+                __reduce59(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            60 => {
+                __reduce60(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            61 => {
+                __reduce61(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            62 => {
+                __reduce62(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            63 => {
+                __reduce63(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            64 => {
+                __reduce64(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            65 => {
+                __reduce65(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            66 => {
+                __reduce66(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            67 => {
+                __reduce67(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            68 => {
+                __reduce68(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            69 => {
+                __reduce69(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            70 => {
+                __reduce70(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            71 => {
+                __reduce71(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce72(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce73(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce74(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce75(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce76(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce77(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce78(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce79(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce80(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce81(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce82(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce83(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce84(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             85 => {
-                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce85(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             86 => {
-                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce86(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce87(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce88(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce89(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce90(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce91(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce92(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce93(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce94(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce95(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce96(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             97 => {
-                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce97(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             98 => {
-                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce98(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             99 => {
-                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce99(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             100 => {
+                __reduce100(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            101 => {
+                __reduce101(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            102 => {
+                __reduce102(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            103 => {
+                __reduce103(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            104 => {
+                __reduce104(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            105 => {
+                __reduce105(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            106 => {
+                __reduce106(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            107 => {
+                __reduce107(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            108 => {
+                __reduce108(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            109 => {
+                __reduce109(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            110 => {
+                __reduce110(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            111 => {
+                __reduce111(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            112 => {
+                __reduce112(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            113 => {
+                __reduce113(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            114 => {
+                __reduce114(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            115 => {
+                __reduce115(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            116 => {
+                __reduce116(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            117 => {
+                __reduce117(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            148 => {
+                __reduce148(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            149 => {
+                __reduce149(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            150 => {
+                __reduce150(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            151 => {
+                __reduce151(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            152 => {
+                __reduce152(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            153 => {
+                __reduce153(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            154 => {
+                __reduce154(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            155 => {
+                __reduce155(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            156 => {
+                __reduce156(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            157 => {
+                __reduce157(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            158 => {
+                __reduce158(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            159 => {
+                __reduce159(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            160 => {
+                __reduce160(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            161 => {
+                __reduce161(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            162 => {
+                __reduce162(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            163 => {
+                __reduce163(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            164 => {
+                __reduce164(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            165 => {
+                __reduce165(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            166 => {
+                __reduce166(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            167 => {
+                __reduce167(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            168 => {
                 // __CompUnit = CompUnit => ActionFn(0);
-                let __sym0 = __pop_Variant22(__symbols);
+                let __sym0 = __pop_Variant32(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action0::<>(errors, input, __sym0);
                 return Some(Ok(__nt));
             }
             _ => panic!("invalid action code {}", __action)
@@ -1889,432 +2965,586 @@ mod __parse__CompUnit {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant19<
+    fn __pop_Variant26<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, AddExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, AddOp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant28<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, BType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Block, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant30<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, BlockItem, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant32<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, CompUnit, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant33<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Decl, usize)
+    ) -> (usize, ConstDecl, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant2<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Def, usize)
+    ) -> (usize, ConstDef, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant17<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, EqExp, usize)
+    ) -> (usize, ConstExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant25<
+    fn __pop_Variant4<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, EqOp, usize)
+    ) -> (usize, ConstInitVal, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant34<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Exp, usize)
+    ) -> (usize, Decl, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Def, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant35<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, EqExp, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant36<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, EqOp, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Exp, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant38<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FuncDef, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant10<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FuncFParam, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FuncFParams, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant21<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FuncRParams, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant26<
+    fn __pop_Variant39<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FuncType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant39(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant23<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, GlobalItem, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, InitVal, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant29<
+    fn __pop_Variant42<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, LAndExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant42(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant30<
+    fn __pop_Variant43<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, LOrExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant43(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant31<
+    fn __pop_Variant44<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, LVal, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant44(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant32<
+    fn __pop_Variant46<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, MulExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant46(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant33<
+    fn __pop_Variant47<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, MulOp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant47(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant48<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Number, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant48(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant37<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<Block>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant18<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Option<Exp>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<FuncFParams>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<FuncRParams>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<InitVal>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant34<
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Option<alloc::vec::Vec<ConstExp>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant49<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, PrimaryExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant49(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant35<
+    fn __pop_Variant50<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, RelExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant50(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant36<
+    fn __pop_Variant51<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, RelOp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant51(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant37<
+    fn __pop_Variant45<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Stmt, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant45(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant40<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, String, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant40(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant38<
+    fn __pop_Variant52<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, UnaryExp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant52(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant39<
+    fn __pop_Variant53<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, UnaryOp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant39(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant53(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant31<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<BlockItem>, usize)
+    ) -> (usize, Vec<BlockItem>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<ConstDef>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<ConstExp>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<ConstInitVal>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<Def>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<Exp>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant11<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<FuncDef>, usize)
+    ) -> (usize, alloc::vec::Vec<FuncFParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<FuncFParam>, usize)
+    ) -> (usize, alloc::vec::Vec<GlobalItem>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant28<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i32, usize)
+    ) -> (usize, alloc::vec::Vec<InitVal>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant41<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant41(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2331,3081 +3561,9991 @@ mod __parse__CompUnit {
     }
     fn __reduce0<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Def>) = ",", Def => ActionFn(78);
+        // ("," <ConstDef>) = ",", ConstDef => ActionFn(123);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action123::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (2, 0)
     }
     fn __reduce1<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Def>)* =  => ActionFn(76);
+        // ("," <ConstDef>)* =  => ActionFn(121);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action76::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action121::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (0, 1)
     }
     fn __reduce2<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Def>)* = ("," <Def>)+ => ActionFn(77);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ("," <ConstDef>)* = ("," <ConstDef>)+ => ActionFn(122);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action77::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action122::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 1)
     }
     fn __reduce3<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Def>)+ = ",", Def => ActionFn(93);
+        // ("," <ConstDef>)+ = ",", ConstDef => ActionFn(148);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action93::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action148::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (2, 2)
     }
     fn __reduce4<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Def>)+ = ("," <Def>)+, ",", Def => ActionFn(94);
+        // ("," <ConstDef>)+ = ("," <ConstDef>)+, ",", ConstDef => ActionFn(149);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action94::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action149::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (3, 2)
     }
     fn __reduce5<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Exp>) = ",", Exp => ActionFn(60);
+        // ("," <ConstInitVal>) = ",", ConstInitVal => ActionFn(117);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action60::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        let __nt = super::__action117::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (2, 3)
     }
     fn __reduce6<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Exp>)* =  => ActionFn(58);
+        // ("," <ConstInitVal>)* =  => ActionFn(115);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action58::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action115::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (0, 4)
     }
     fn __reduce7<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Exp>)* = ("," <Exp>)+ => ActionFn(59);
-        let __sym0 = __pop_Variant4(__symbols);
+        // ("," <ConstInitVal>)* = ("," <ConstInitVal>)+ => ActionFn(116);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action59::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action116::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (1, 4)
     }
     fn __reduce8<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Exp>)+ = ",", Exp => ActionFn(97);
+        // ("," <ConstInitVal>)+ = ",", ConstInitVal => ActionFn(152);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action97::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action152::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (2, 5)
     }
     fn __reduce9<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Exp>)+ = ("," <Exp>)+, ",", Exp => ActionFn(98);
+        // ("," <ConstInitVal>)+ = ("," <ConstInitVal>)+, ",", ConstInitVal => ActionFn(153);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action98::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action153::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (3, 5)
     }
     fn __reduce10<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <FuncFParam>) = ",", FuncFParam => ActionFn(63);
+        // ("," <Def>) = ",", Def => ActionFn(114);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action63::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        let __nt = super::__action114::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (2, 6)
     }
     fn __reduce11<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <FuncFParam>)* =  => ActionFn(61);
+        // ("," <Def>)* =  => ActionFn(112);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action61::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action112::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (0, 7)
     }
     fn __reduce12<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <FuncFParam>)* = ("," <FuncFParam>)+ => ActionFn(62);
-        let __sym0 = __pop_Variant6(__symbols);
+        // ("," <Def>)* = ("," <Def>)+ => ActionFn(113);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action113::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (1, 7)
     }
     fn __reduce13<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <FuncFParam>)+ = ",", FuncFParam => ActionFn(101);
+        // ("," <Def>)+ = ",", Def => ActionFn(156);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action101::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action156::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (2, 8)
     }
     fn __reduce14<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <FuncFParam>)+ = ("," <FuncFParam>)+, ",", FuncFParam => ActionFn(102);
+        // ("," <Def>)+ = ("," <Def>)+, ",", Def => ActionFn(157);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action102::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action157::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (3, 8)
     }
     fn __reduce15<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("=" <InitVal>) = "=", InitVal => ActionFn(75);
+        // ("," <Exp>) = ",", Exp => ActionFn(90);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action75::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        let __nt = super::__action90::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (2, 9)
     }
     fn __reduce16<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("=" <InitVal>)? = "=", InitVal => ActionFn(105);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action105::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 10)
+        // ("," <Exp>)* =  => ActionFn(88);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action88::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 10)
     }
     fn __reduce17<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("=" <InitVal>)? =  => ActionFn(74);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action74::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 10)
+        // ("," <Exp>)* = ("," <Exp>)+ => ActionFn(89);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action89::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 10)
     }
     fn __reduce18<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("else" <Block>) = "else", Block => ActionFn(69);
+        // ("," <Exp>)+ = ",", Exp => ActionFn(160);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action69::<>(input, __sym0, __sym1);
+        let __nt = super::__action160::<>(errors, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (2, 11)
     }
     fn __reduce19<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("else" <Block>)? = "else", Block => ActionFn(108);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant9(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ("," <Exp>)+ = ("," <Exp>)+, ",", Exp => ActionFn(161);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action108::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 12)
+        let __end = __sym2.2;
+        let __nt = super::__action161::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 11)
     }
     fn __reduce20<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("else" <Block>)? =  => ActionFn(68);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action68::<>(input, &__start, &__end);
+        // ("," <FuncFParam>) = ",", FuncFParam => ActionFn(96);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action96::<>(errors, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (0, 12)
+        (2, 12)
     }
     fn __reduce21<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<BlockItem>) = BlockItem => ActionFn(72);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action72::<>(input, __sym0);
+        // ("," <FuncFParam>)* =  => ActionFn(94);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action94::<>(errors, input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 13)
+        (0, 13)
     }
     fn __reduce22<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<BlockItem>)* =  => ActionFn(70);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action70::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 14)
+        // ("," <FuncFParam>)* = ("," <FuncFParam>)+ => ActionFn(95);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action95::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 13)
     }
     fn __reduce23<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<BlockItem>)* = (<BlockItem>)+ => ActionFn(71);
-        let __sym0 = __pop_Variant12(__symbols);
+        // ("," <FuncFParam>)+ = ",", FuncFParam => ActionFn(164);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action71::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 14)
+        let __end = __sym1.2;
+        let __nt = super::__action164::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 14)
     }
     fn __reduce24<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<BlockItem>)+ = BlockItem => ActionFn(111);
+        // ("," <FuncFParam>)+ = ("," <FuncFParam>)+, ",", FuncFParam => ActionFn(165);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action111::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 15)
+        let __end = __sym2.2;
+        let __nt = super::__action165::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 14)
     }
     fn __reduce25<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<BlockItem>)+ = (<BlockItem>)+, BlockItem => ActionFn(112);
+        // ("," <InitVal>) = ",", InitVal => ActionFn(108);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action112::<>(input, __sym0, __sym1);
+        let __nt = super::__action108::<>(errors, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (2, 15)
     }
     fn __reduce26<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncDef) = FuncDef => ActionFn(84);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action84::<>(input, __sym0);
+        // ("," <InitVal>)* =  => ActionFn(106);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action106::<>(errors, input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 16)
+        (0, 16)
     }
     fn __reduce27<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncDef)+ = FuncDef => ActionFn(115);
+        // ("," <InitVal>)* = ("," <InitVal>)+ => ActionFn(107);
         let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action115::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 17)
+        let __nt = super::__action107::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 16)
     }
     fn __reduce28<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncDef)+ = (FuncDef)+, FuncDef => ActionFn(116);
+        // ("," <InitVal>)+ = ",", InitVal => ActionFn(168);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action116::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        let __nt = super::__action168::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (2, 17)
     }
     fn __reduce29<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncFParams) = FuncFParams => ActionFn(66);
-        let __sym0 = __pop_Variant15(__symbols);
+        // ("," <InitVal>)+ = ("," <InitVal>)+, ",", InitVal => ActionFn(169);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action66::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2;
+        let __nt = super::__action169::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 17)
     }
     fn __reduce30<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncFParams)? = FuncFParams => ActionFn(117);
-        let __sym0 = __pop_Variant15(__symbols);
+        // ("=" <InitVal>) = "=", InitVal => ActionFn(111);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action117::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 19)
+        let __end = __sym1.2;
+        let __nt = super::__action111::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 18)
     }
     fn __reduce31<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncFParams)? =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 19)
+        // ("=" <InitVal>)? = "=", InitVal => ActionFn(172);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action172::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 19)
     }
     fn __reduce32<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncRParams) = FuncRParams => ActionFn(81);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action81::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 20)
+        // ("=" <InitVal>)? =  => ActionFn(110);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action110::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 19)
     }
     fn __reduce33<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncRParams)? = FuncRParams => ActionFn(120);
-        let __sym0 = __pop_Variant17(__symbols);
+        // ("[" "]" <("[" <ConstExp> "]")*>) = "[", "]" => ActionFn(175);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action120::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 21)
+        let __end = __sym1.2;
+        let __nt = super::__action175::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 20)
     }
     fn __reduce34<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FuncRParams)? =  => ActionFn(80);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action80::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (0, 21)
+        // ("[" "]" <("[" <ConstExp> "]")*>) = "[", "]", ("[" <ConstExp> "]")+ => ActionFn(176);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action176::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 20)
     }
     fn __reduce35<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // AddExp = MulExp => ActionFn(16);
-        let __sym0 = __pop_Variant32(__symbols);
+        // ("[" "]" <("[" <ConstExp> "]")*>)? = "[", "]" => ActionFn(183);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 22)
+        let __end = __sym1.2;
+        let __nt = super::__action183::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (2, 21)
     }
     fn __reduce36<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // AddExp = AddExp, AddOp, MulExp => ActionFn(17);
+        // ("[" "]" <("[" <ConstExp> "]")*>)? = "[", "]", ("[" <ConstExp> "]")+ => ActionFn(184);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant32(__symbols);
-        let __sym1 = __pop_Variant20(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (3, 22)
+        let __nt = super::__action184::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 21)
     }
     fn __reduce37<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // AddOp = "+" => ActionFn(32);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 23)
+        // ("[" "]" <("[" <ConstExp> "]")*>)? =  => ActionFn(92);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action92::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 21)
     }
     fn __reduce38<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // AddOp = "-" => ActionFn(33);
+        // ("[" <ConstExp> "]") = "[", ConstExp, "]" => ActionFn(120);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 23)
+        let __end = __sym2.2;
+        let __nt = super::__action120::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (3, 22)
     }
     fn __reduce39<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BType = "int" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 24)
+        // ("[" <ConstExp> "]")* =  => ActionFn(118);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action118::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 23)
     }
     fn __reduce40<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Block = "{", "}" => ActionFn(113);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ("[" <ConstExp> "]")* = ("[" <ConstExp> "]")+ => ActionFn(119);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action113::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 25)
+        let __end = __sym0.2;
+        let __nt = super::__action119::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
     }
     fn __reduce41<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Block = "{", (<BlockItem>)+, "}" => ActionFn(114);
+        // ("[" <ConstExp> "]")+ = "[", ConstExp, "]" => ActionFn(188);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action114::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (3, 25)
+        let __nt = super::__action188::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 24)
     }
     fn __reduce42<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BlockItem = Decl => ActionFn(46);
-        let __sym0 = __pop_Variant23(__symbols);
+        // ("[" <ConstExp> "]")+ = ("[" <ConstExp> "]")+, "[", ConstExp, "]" => ActionFn(189);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant17(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action46::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 26)
+        let __end = __sym3.2;
+        let __nt = super::__action189::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 24)
     }
     fn __reduce43<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BlockItem = Stmt => ActionFn(47);
-        let __sym0 = __pop_Variant37(__symbols);
+        // ("[" <Exp> "]") = "[", Exp, "]" => ActionFn(105);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action47::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 26)
+        let __end = __sym2.2;
+        let __nt = super::__action105::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 25)
     }
     fn __reduce44<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CompUnit = (FuncDef)+ => ActionFn(1);
-        let __sym0 = __pop_Variant14(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 27)
+        // ("[" <Exp> "]")* =  => ActionFn(103);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action103::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 26)
     }
     fn __reduce45<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Decl = BType, Def, ";" => ActionFn(95);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
+        // ("[" <Exp> "]")* = ("[" <Exp> "]")+ => ActionFn(104);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action95::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (3, 28)
+        let __end = __sym0.2;
+        let __nt = super::__action104::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 26)
     }
     fn __reduce46<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Decl = BType, Def, ("," <Def>)+, ";" => ActionFn(96);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
+        // ("[" <Exp> "]")+ = "[", Exp, "]" => ActionFn(190);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action96::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (4, 28)
+        let __end = __sym2.2;
+        let __nt = super::__action190::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 27)
     }
     fn __reduce47<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Def = Ident, "=", InitVal => ActionFn(106);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant7(__symbols);
+        // ("[" <Exp> "]")+ = ("[" <Exp> "]")+, "[", Exp, "]" => ActionFn(191);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action106::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 29)
+        let __end = __sym3.2;
+        let __nt = super::__action191::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (4, 27)
     }
     fn __reduce48<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Def = Ident => ActionFn(107);
-        let __sym0 = __pop_Variant27(__symbols);
+        // (Exp) = Exp => ActionFn(102);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        let __nt = super::__action102::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 28)
     }
     fn __reduce49<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // EqExp = RelExp => ActionFn(20);
-        let __sym0 = __pop_Variant35(__symbols);
+        // (Exp)? = Exp => ActionFn(194);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 30)
+        let __nt = super::__action194::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 29)
     }
     fn __reduce50<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // EqExp = EqExp, EqOp, RelExp => ActionFn(21);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant35(__symbols);
-        let __sym1 = __pop_Variant25(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action21::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (3, 30)
+        // (Exp)? =  => ActionFn(101);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action101::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 29)
     }
     fn __reduce51<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // EqOp = "==" => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (FuncFParams) = FuncFParams => ActionFn(99);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 31)
+        let __nt = super::__action99::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 30)
     }
     fn __reduce52<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // EqOp = "!=" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (FuncFParams)? = FuncFParams => ActionFn(199);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        let __nt = super::__action199::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (1, 31)
     }
     fn __reduce53<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = LOrExp => ActionFn(7);
-        let __sym0 = __pop_Variant30(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 32)
+        // (FuncFParams)? =  => ActionFn(98);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action98::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 31)
     }
     fn __reduce54<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncDef = FuncType, Ident, "(", FuncFParams, ")", Block => ActionFn(118);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant9(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant27(__symbols);
-        let __sym0 = __pop_Variant26(__symbols);
+        // (FuncRParams) = FuncRParams => ActionFn(126);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action118::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (6, 33)
+        let __end = __sym0.2;
+        let __nt = super::__action126::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 32)
     }
     fn __reduce55<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncDef = FuncType, Ident, "(", ")", Block => ActionFn(119);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant9(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant27(__symbols);
-        let __sym0 = __pop_Variant26(__symbols);
+        // (FuncRParams)? = FuncRParams => ActionFn(202);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (5, 33)
+        let __end = __sym0.2;
+        let __nt = super::__action202::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 33)
     }
     fn __reduce56<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncFParam = BType, Ident => ActionFn(56);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant27(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action56::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 34)
+        // (FuncRParams)? =  => ActionFn(125);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action125::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 33)
     }
     fn __reduce57<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncFParams = FuncFParam => ActionFn(103);
-        let __sym0 = __pop_Variant5(__symbols);
+        // (GlobalItem) = GlobalItem => ActionFn(131);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action103::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 35)
+        let __nt = super::__action131::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 34)
     }
     fn __reduce58<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncFParams = FuncFParam, ("," <FuncFParam>)+ => ActionFn(104);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // (GlobalItem)+ = GlobalItem => ActionFn(205);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action104::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 35)
+        let __end = __sym0.2;
+        let __nt = super::__action205::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 35)
     }
     fn __reduce59<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncRParams = Exp => ActionFn(99);
-        let __sym0 = __pop_Variant3(__symbols);
+        // (GlobalItem)+ = (GlobalItem)+, GlobalItem => ActionFn(206);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action99::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 36)
+        let __end = __sym1.2;
+        let __nt = super::__action206::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (2, 35)
     }
     fn __reduce60<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncRParams = Exp, ("," <Exp>)+ => ActionFn(100);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant4(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action100::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (2, 36)
+        // @L =  => ActionFn(128);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action128::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 36)
     }
     fn __reduce61<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FuncType = "int" => ActionFn(54);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 37)
+        // @R =  => ActionFn(127);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action127::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 37)
     }
     fn __reduce62<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Ident = r#"[_a-zA-Z][_a-zA-Z0-9]*"# => ActionFn(3);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AddExp = MulExp => ActionFn(20);
+        let __sym0 = __pop_Variant46(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action3::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        let __nt = super::__action20::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 38)
     }
     fn __reduce63<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // InitVal = Exp => ActionFn(42);
-        let __sym0 = __pop_Variant3(__symbols);
+        // AddExp = AddExp, AddOp, MulExp => ActionFn(21);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant46(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 39)
+        let __end = __sym2.2;
+        let __nt = super::__action21::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (3, 38)
     }
     fn __reduce64<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IntConst = r#"[1-9][0-9]*"# => ActionFn(4);
+        // AddOp = "+" => ActionFn(36);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 40)
+        let __nt = super::__action36::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 39)
     }
     fn __reduce65<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IntConst = r#"0[0-7]*"# => ActionFn(5);
+        // AddOp = "-" => ActionFn(37);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 40)
+        let __nt = super::__action37::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 39)
     }
     fn __reduce66<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IntConst = r#"0[xX][0-9a-fA-F]+"# => ActionFn(6);
+        // BType = "int" => ActionFn(55);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action6::<>(input, __sym0);
+        let __nt = super::__action55::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant28(__nt), __end));
         (1, 40)
     }
     fn __reduce67<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAndExp = EqExp => ActionFn(22);
-        let __sym0 = __pop_Variant24(__symbols);
+        // Block = "{", BlockItems, "}" => ActionFn(248);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action22::<>(input, __sym0);
+        let __end = __sym2.2;
+        let __nt = super::__action248::<>(errors, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (1, 41)
+        (3, 41)
     }
     fn __reduce68<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAndExp = LAndExp, "&&", EqExp => ActionFn(23);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant24(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant29(__symbols);
+        // Block = "{", BlockItems, error, "}" => ActionFn(249);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action23::<>(input, __sym0, __sym1, __sym2);
+        let __end = __sym3.2;
+        let __nt = super::__action249::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (3, 41)
+        (4, 41)
     }
     fn __reduce69<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LOrExp = LAndExp => ActionFn(24);
-        let __sym0 = __pop_Variant29(__symbols);
+        // BlockItem = ConstDecl => ActionFn(60);
+        let __sym0 = __pop_Variant33(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action24::<>(input, __sym0);
+        let __nt = super::__action60::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant30(__nt), __end));
         (1, 42)
     }
     fn __reduce70<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LOrExp = LOrExp, "||", LAndExp => ActionFn(25);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant29(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant30(__symbols);
+        // BlockItem = Decl => ActionFn(61);
+        let __sym0 = __pop_Variant34(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action25::<>(input, __sym0, __sym1, __sym2);
+        let __end = __sym0.2;
+        let __nt = super::__action61::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (3, 42)
+        (1, 42)
     }
     fn __reduce71<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LVal = Ident => ActionFn(43);
-        let __sym0 = __pop_Variant27(__symbols);
+        // BlockItem = Stmt => ActionFn(62);
+        let __sym0 = __pop_Variant45(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (1, 43)
+        let __nt = super::__action62::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 42)
     }
     fn __reduce72<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // MulExp = UnaryExp => ActionFn(14);
-        let __sym0 = __pop_Variant38(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 44)
+        // BlockItems =  => ActionFn(58);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action58::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (0, 43)
     }
     fn __reduce73<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // MulExp = MulExp, MulOp, UnaryExp => ActionFn(15);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant38(__symbols);
-        let __sym1 = __pop_Variant33(__symbols);
-        let __sym0 = __pop_Variant32(__symbols);
+        // BlockItems = BlockItems, BlockItem => ActionFn(59);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant30(__symbols);
+        let __sym0 = __pop_Variant31(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action15::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (3, 44)
+        let __end = __sym1.2;
+        let __nt = super::__action59::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (2, 43)
     }
     fn __reduce74<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // MulOp = "*" => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CompUnit = (GlobalItem)+ => ActionFn(1);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        let __nt = super::__action1::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 44)
     }
     fn __reduce75<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // MulOp = "/" => ActionFn(30);
+        // ConstDecl = "const", "int", ConstDef, ";" => ActionFn(289);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
+        let __end = __sym3.2;
+        let __nt = super::__action289::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        (4, 45)
     }
     fn __reduce76<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // MulOp = "%" => ActionFn(31);
+        // ConstDecl = "const", "int", ConstDef, ("," <ConstDef>)+, ";" => ActionFn(290);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action31::<>(input, __sym0);
+        let __end = __sym4.2;
+        let __nt = super::__action290::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        (5, 45)
     }
     fn __reduce77<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Number = IntConst => ActionFn(2);
-        let __sym0 = __pop_Variant28(__symbols);
+        // ConstDef = Ident, "=", ConstInitVal => ActionFn(252);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 46)
+        let __end = __sym2.2;
+        let __nt = super::__action252::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (3, 46)
     }
     fn __reduce78<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PrimaryExp = "(", Exp, ")" => ActionFn(8);
-        assert!(__symbols.len() >= 3);
+        // ConstDef = Ident, ("[" <ConstExp> "]")+, "=", ConstInitVal => ActionFn(253);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant4(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (3, 47)
+        let __end = __sym3.2;
+        let __nt = super::__action253::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (4, 46)
     }
     fn __reduce79<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PrimaryExp = LVal => ActionFn(9);
-        let __sym0 = __pop_Variant31(__symbols);
+        // ConstExp = AddExp => ActionFn(254);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action9::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        let __nt = super::__action254::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
         (1, 47)
     }
     fn __reduce80<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PrimaryExp = Number => ActionFn(10);
-        let __sym0 = __pop_Variant28(__symbols);
+        // ConstInitVal = ConstExp => ActionFn(46);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (1, 47)
+        let __nt = super::__action46::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 48)
     }
     fn __reduce81<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelExp = AddExp => ActionFn(18);
-        let __sym0 = __pop_Variant19(__symbols);
+        // ConstInitVal = "{", "}" => ActionFn(47);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
-        (1, 48)
+        let __end = __sym1.2;
+        let __nt = super::__action47::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 48)
     }
     fn __reduce82<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelExp = RelExp, RelOp, AddExp => ActionFn(19);
+        // ConstInitVal = "{", ConstInitVal, "}" => ActionFn(154);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant19(__symbols);
-        let __sym1 = __pop_Variant36(__symbols);
-        let __sym0 = __pop_Variant35(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action19::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        let __nt = super::__action154::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (3, 48)
     }
     fn __reduce83<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelOp = "<" => ActionFn(34);
+        // ConstInitVal = "{", ConstInitVal, ("," <ConstInitVal>)+, "}" => ActionFn(155);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
-        (1, 49)
+        let __end = __sym3.2;
+        let __nt = super::__action155::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 48)
     }
     fn __reduce84<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelOp = ">" => ActionFn(35);
+        // Decl = "int", Def, ";" => ActionFn(291);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
-        (1, 49)
+        let __end = __sym2.2;
+        let __nt = super::__action291::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (3, 49)
     }
     fn __reduce85<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelOp = "<=" => ActionFn(36);
+        // Decl = "int", Def, ("," <Def>)+, ";" => ActionFn(292);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action36::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
-        (1, 49)
+        let __end = __sym3.2;
+        let __nt = super::__action292::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (4, 49)
     }
     fn __reduce86<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelOp = ">=" => ActionFn(37);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Def = Ident, "=", InitVal => ActionFn(257);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
-        (1, 49)
+        let __end = __sym2.2;
+        let __nt = super::__action257::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 50)
     }
     fn __reduce87<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = LVal, "=", Exp, ";" => ActionFn(48);
+        // Def = Ident, ("[" <ConstExp> "]")+, "=", InitVal => ActionFn(258);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant31(__symbols);
+        let __sym3 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action48::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        let __nt = super::__action258::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (4, 50)
     }
     fn __reduce88<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = Block => ActionFn(49);
-        let __sym0 = __pop_Variant9(__symbols);
+        // Def = Ident => ActionFn(259);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        let __nt = super::__action259::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (1, 50)
     }
     fn __reduce89<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = "if", "(", Exp, ")", Block, "else", Block => ActionFn(109);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant9(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant9(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Def = Ident, ("[" <ConstExp> "]")+ => ActionFn(260);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action260::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 50)
+    }
+    fn __reduce90<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // EqExp = RelExp => ActionFn(24);
+        let __sym0 = __pop_Variant50(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action24::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 51)
+    }
+    fn __reduce91<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // EqExp = EqExp, EqOp, RelExp => ActionFn(25);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant50(__symbols);
+        let __sym1 = __pop_Variant36(__symbols);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action25::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (3, 51)
+    }
+    fn __reduce92<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // EqOp = "==" => ActionFn(42);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action109::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __end = __sym0.2;
+        let __nt = super::__action42::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 52)
+    }
+    fn __reduce93<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // EqOp = "!=" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action43::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 52)
+    }
+    fn __reduce94<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Exp = LOrExp => ActionFn(261);
+        let __sym0 = __pop_Variant43(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action261::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 53)
+    }
+    fn __reduce95<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FuncBody = Block => ActionFn(81);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action81::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant37(__nt), __end));
-        (7, 50)
+        (1, 54)
     }
-    fn __reduce90<
+    fn __reduce96<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FuncBody = ";" => ActionFn(82);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action82::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (1, 54)
+    }
+    fn __reduce97<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FuncDef = "int", Ident, "(", FuncFParams, ")", FuncBody => ActionFn(296);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant37(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant40(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action296::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (6, 55)
+    }
+    fn __reduce98<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FuncDef = "void", Ident, "(", FuncFParams, ")", FuncBody => ActionFn(297);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant37(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant40(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action297::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (6, 55)
+    }
+    fn __reduce99<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = "if", "(", Exp, ")", Block => ActionFn(110);
+        // FuncDef = "int", Ident, "(", ")", FuncBody => ActionFn(298);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant9(__symbols);
+        let __sym4 = __pop_Variant37(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant40(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action110::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
-        (5, 50)
+        let __nt = super::__action298::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (5, 55)
     }
-    fn __reduce91<
+    fn __reduce100<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = "while", "(", Exp, ")", Block => ActionFn(51);
+        // FuncDef = "void", Ident, "(", ")", FuncBody => ActionFn(299);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant9(__symbols);
+        let __sym4 = __pop_Variant37(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant40(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action51::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
-        (5, 50)
+        let __nt = super::__action299::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (5, 55)
     }
-    fn __reduce92<
+    fn __reduce101<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = "return", Exp, ";" => ActionFn(52);
-        assert!(__symbols.len() >= 3);
+        // FuncFParam = "int", Ident, "[", "]" => ActionFn(293);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant40(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
-        (3, 50)
+        let __end = __sym3.2;
+        let __nt = super::__action293::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (4, 56)
     }
-    fn __reduce93<
+    fn __reduce102<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryExp = PrimaryExp => ActionFn(11);
-        let __sym0 = __pop_Variant34(__symbols);
+        // FuncFParam = "int", Ident, "[", "]", ("[" <ConstExp> "]")+ => ActionFn(294);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant15(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant40(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym4.2;
+        let __nt = super::__action294::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (5, 56)
+    }
+    fn __reduce103<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FuncFParam = "int", Ident => ActionFn(295);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant40(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action295::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 56)
+    }
+    fn __reduce104<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FuncFParams = FuncFParam => ActionFn(166);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (1, 51)
+        let __nt = super::__action166::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 57)
     }
-    fn __reduce94<
+    fn __reduce105<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryExp = Ident, "(", FuncRParams, ")" => ActionFn(121);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant17(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant27(__symbols);
+        // FuncFParams = FuncFParam, ("," <FuncFParam>)+ => ActionFn(167);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action121::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (4, 51)
+        let __end = __sym1.2;
+        let __nt = super::__action167::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 57)
     }
-    fn __reduce95<
+    fn __reduce106<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryExp = Ident, "(", ")" => ActionFn(122);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant27(__symbols);
+        // FuncRParams = Exp => ActionFn(162);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action122::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (3, 51)
+        let __end = __sym0.2;
+        let __nt = super::__action162::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 58)
     }
-    fn __reduce96<
+    fn __reduce107<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryExp = UnaryOp, UnaryExp => ActionFn(13);
+        // FuncRParams = Exp, ("," <Exp>)+ => ActionFn(163);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant38(__symbols);
-        let __sym0 = __pop_Variant39(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action13::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (2, 51)
+        let __nt = super::__action163::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 58)
     }
-    fn __reduce97<
+    fn __reduce108<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "+" => ActionFn(26);
+        // FuncType = "int" => ActionFn(83);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, __sym0);
+        let __nt = super::__action83::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (1, 52)
+        (1, 59)
     }
-    fn __reduce98<
+    fn __reduce109<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "-" => ActionFn(27);
+        // FuncType = "void" => ActionFn(84);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
+        let __nt = super::__action84::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (1, 52)
+        (1, 59)
     }
-    fn __reduce99<
+    fn __reduce110<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // GlobalItem = ConstDecl => ActionFn(2);
+        let __sym0 = __pop_Variant33(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action2::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 60)
+    }
+    fn __reduce111<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // GlobalItem = Decl => ActionFn(3);
+        let __sym0 = __pop_Variant34(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action3::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 60)
+    }
+    fn __reduce112<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // GlobalItem = FuncDef => ActionFn(4);
+        let __sym0 = __pop_Variant38(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action4::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 60)
+    }
+    fn __reduce113<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "!" => ActionFn(28);
+        // Ident = r#"[_a-zA-Z][_a-zA-Z0-9]*"# => ActionFn(6);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (1, 52)
+        let __nt = super::__action6::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 61)
     }
-}
-#[allow(unused_imports)]
-pub use self::__parse__CompUnit::CompUnitParser;
-#[rustfmt::skip]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use crate::ast::*;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    #[allow(unused_extern_crates)]
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("(?:(?://)[\0-\t\u{b}\u{c}\u{e}-\u{10ffff}]*[\n\r]*)", true),
-            ("(?:(?:/\\*)[\0-\\)\\+-\u{10ffff}]*\\*+(?:[\0-\\)\\+-\\.0-\u{10ffff}][\0-\\)\\+-\u{10ffff}]*\\*+)*/)", true),
-            ("(?:0[0-7]*)", false),
-            ("(?:0[Xx][0-9A-Fa-f]+)", false),
-            ("(?:[1-9][0-9]*)", false),
-            ("(?:[A-Z_a-z][0-9A-Z_a-z]*)", false),
-            ("[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}\u{2029}\u{202f}\u{205f}\u{3000}]*", true),
-            ("!", false),
-            ("(?:!=)", false),
-            ("%", false),
-            ("(?:\\&\\&)", false),
-            ("\\(", false),
-            ("\\)", false),
-            ("\\*", false),
-            ("\\+", false),
-            (",", false),
-            ("\\-", false),
-            ("/", false),
-            (";", false),
-            ("<", false),
-            ("(?:<=)", false),
-            ("=", false),
-            ("(?:==)", false),
-            (">", false),
-            ("(?:>=)", false),
-            ("(?:else)", false),
-            ("(?:if)", false),
-            ("(?:int)", false),
-            ("(?:return)", false),
-            ("(?:while)", false),
-            ("\\{", false),
-            ("(?:\\|\\|)", false),
-            ("\\}", false),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    fn __reduce114<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // InitVal = Exp => ActionFn(51);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action51::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 62)
+    }
+    fn __reduce115<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // InitVal = "{", "}" => ActionFn(52);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action52::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 62)
+    }
+    fn __reduce116<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // InitVal = "{", InitVal, "}" => ActionFn(170);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action170::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 62)
+    }
+    fn __reduce117<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // InitVal = "{", InitVal, ("," <InitVal>)+, "}" => ActionFn(171);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action171::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 62)
+    }
+    fn __reduce118<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IntConst = r#"[1-9][0-9]*"# => ActionFn(7);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action7::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (1, 63)
+    }
+    fn __reduce119<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IntConst = r#"0[0-7]*"# => ActionFn(8);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (1, 63)
+    }
+    fn __reduce120<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IntConst = r#"0[xX][0-9a-fA-F]+"# => ActionFn(9);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action9::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (1, 63)
+    }
+    fn __reduce121<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAndExp = EqExp => ActionFn(26);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action26::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (1, 64)
+    }
+    fn __reduce122<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAndExp = LAndExp, "&&", EqExp => ActionFn(27);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant35(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant42(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action27::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (3, 64)
+    }
+    fn __reduce123<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LOrExp = LAndExp => ActionFn(28);
+        let __sym0 = __pop_Variant42(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action28::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (1, 65)
+    }
+    fn __reduce124<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LOrExp = LOrExp, "||", LAndExp => ActionFn(29);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant42(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant43(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action29::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (3, 65)
+    }
+    fn __reduce125<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LVal = Ident => ActionFn(267);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action267::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 66)
+    }
+    fn __reduce126<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LVal = Ident, ("[" <Exp> "]")+ => ActionFn(268);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action268::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (2, 66)
+    }
+    fn __reduce127<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MatchedStmt = SimpleStmt => ActionFn(65);
+        let __sym0 = __pop_Variant45(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action65::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 67)
+    }
+    fn __reduce128<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MatchedStmt = "if", "(", Exp, ")", MatchedStmt, "else", MatchedStmt => ActionFn(269);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant45(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant45(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action269::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (7, 67)
+    }
+    fn __reduce129<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MatchedStmt = "while", "(", Exp, ")", MatchedStmt => ActionFn(270);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant45(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym4.2;
+        let __nt = super::__action270::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (5, 67)
+    }
+    fn __reduce130<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulExp = UnaryExp => ActionFn(18);
+        let __sym0 = __pop_Variant52(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action18::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (1, 68)
     }
+    fn __reduce131<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulExp = MulExp, MulOp, UnaryExp => ActionFn(271);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant52(__symbols);
+        let __sym1 = __pop_Variant47(__symbols);
+        let __sym0 = __pop_Variant46(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action271::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (3, 68)
+    }
+    fn __reduce132<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulOp = "*" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action33::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
+        (1, 69)
+    }
+    fn __reduce133<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulOp = "/" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action34::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
+        (1, 69)
+    }
+    fn __reduce134<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulOp = "%" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action35::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
+        (1, 69)
+    }
+    fn __reduce135<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Number = IntConst => ActionFn(272);
+        let __sym0 = __pop_Variant41(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action272::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
+        (1, 70)
+    }
+    fn __reduce136<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "if", "(", Exp, ")", Stmt => ActionFn(273);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant45(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym4.2;
+        let __nt = super::__action273::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (5, 71)
+    }
+    fn __reduce137<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "if", "(", Exp, ")", MatchedStmt, "else", OpenStmt => ActionFn(274);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant45(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant45(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action274::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (7, 71)
+    }
+    fn __reduce138<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "while", "(", Exp, ")", OpenStmt => ActionFn(275);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant45(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym4.2;
+        let __nt = super::__action275::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (5, 71)
+    }
+    fn __reduce139<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // PrimaryExp = "(", Exp, ")" => ActionFn(12);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action12::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (3, 72)
+    }
+    fn __reduce140<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // PrimaryExp = LVal => ActionFn(13);
+        let __sym0 = __pop_Variant44(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action13::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (1, 72)
+    }
+    fn __reduce141<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // PrimaryExp = Number => ActionFn(14);
+        let __sym0 = __pop_Variant48(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action14::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (1, 72)
+    }
+    fn __reduce142<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExp = AddExp => ActionFn(22);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action22::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
+        (1, 73)
+    }
+    fn __reduce143<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExp = RelExp, RelOp, AddExp => ActionFn(23);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant26(__symbols);
+        let __sym1 = __pop_Variant51(__symbols);
+        let __sym0 = __pop_Variant50(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action23::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
+        (3, 73)
+    }
+    fn __reduce144<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = "<" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action38::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 74)
+    }
+    fn __reduce145<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = ">" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action39::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 74)
+    }
+    fn __reduce146<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = "<=" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action40::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 74)
+    }
+    fn __reduce147<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = ">=" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action41::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 74)
+    }
+    fn __reduce148<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = LVal, "=", Exp, ";" => ActionFn(276);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant44(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action276::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (4, 75)
+    }
+    fn __reduce149<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = Exp, ";" => ActionFn(277);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action277::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 75)
+    }
+    fn __reduce150<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = ";" => ActionFn(278);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action278::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 75)
+    }
+    fn __reduce151<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = Block => ActionFn(279);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action279::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 75)
+    }
+    fn __reduce152<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "break", ";" => ActionFn(280);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action280::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 75)
+    }
+    fn __reduce153<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "continue", ";" => ActionFn(281);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action281::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 75)
+    }
+    fn __reduce154<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "return", Exp, ";" => ActionFn(282);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action282::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (3, 75)
+    }
+    fn __reduce155<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "return", ";" => ActionFn(283);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action283::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 75)
+    }
+    fn __reduce156<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = error, ";" => ActionFn(284);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action284::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 75)
+    }
+    fn __reduce157<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "if", "(", error, Block => ActionFn(285);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant29(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action285::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (4, 75)
+    }
+    fn __reduce158<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "while", "(", error, Block => ActionFn(286);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant29(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action286::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (4, 75)
+    }
+    fn __reduce159<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = MatchedStmt => ActionFn(63);
+        let __sym0 = __pop_Variant45(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action63::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 76)
+    }
+    fn __reduce160<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = OpenStmt => ActionFn(64);
+        let __sym0 = __pop_Variant45(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action64::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 76)
+    }
+    fn __reduce161<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryExp = PrimaryExp => ActionFn(15);
+        let __sym0 = __pop_Variant49(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action15::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (1, 77)
+    }
+    fn __reduce162<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryExp = Ident, "(", FuncRParams, ")" => ActionFn(287);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action287::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (4, 77)
+    }
+    fn __reduce163<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryExp = Ident, "(", ")" => ActionFn(288);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action288::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (3, 77)
+    }
+    fn __reduce164<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryExp = UnaryOp, UnaryExp => ActionFn(17);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant52(__symbols);
+        let __sym0 = __pop_Variant53(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action17::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (2, 77)
+    }
+    fn __reduce165<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryOp = "+" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action30::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (1, 78)
+    }
+    fn __reduce166<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryOp = "-" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action31::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (1, 78)
+    }
+    fn __reduce167<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryOp = "!" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action32::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (1, 78)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__CompUnit::CompUnitParser;
+#[rustfmt::skip]
+mod __intern_token {
+    #![allow(unused_imports)]
+    use crate::ast::*;
+    use crate::source::Span;
+    use lalrpop_util::ErrorRecovery;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    #[allow(unused_extern_crates)]
+    extern crate alloc;
+    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
+        let __strs: &[(&str, bool)] = &[
+            ("[\0-\t\u{b}-\u{10ffff}]", false),
+            ("(?:(?://)[\0-\t\u{b}\u{c}\u{e}-\u{10ffff}]*[\n\r]*)", true),
+            ("(?:(?:/\\*)[\0-\\)\\+-\u{10ffff}]*\\*+(?:[\0-\\)\\+-\\.0-\u{10ffff}][\0-\\)\\+-\u{10ffff}]*\\*+)*/)", true),
+            ("(?:0[0-7]*)", false),
+            ("(?:0[Xx][0-9A-Fa-f]+)", false),
+            ("(?:[1-9][0-9]*)", false),
+            ("(?:[A-Z_a-z][0-9A-Z_a-z]*)", false),
+            ("[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}\u{2029}\u{202f}\u{205f}\u{3000}]*", true),
+            ("!", false),
+            ("(?:!=)", false),
+            ("%", false),
+            ("(?:\\&\\&)", false),
+            ("\\(", false),
+            ("\\)", false),
+            ("\\*", false),
+            ("\\+", false),
+            (",", false),
+            ("\\-", false),
+            ("/", false),
+            (";", false),
+            ("<", false),
+            ("(?:<=)", false),
+            ("=", false),
+            ("(?:==)", false),
+            (">", false),
+            ("(?:>=)", false),
+            ("\\[", false),
+            ("\\]", false),
+            ("(?:break)", false),
+            ("(?:const)", false),
+            ("(?:continue)", false),
+            ("(?:else)", false),
+            ("(?:if)", false),
+            ("(?:int)", false),
+            ("(?:return)", false),
+            ("(?:void)", false),
+            ("(?:while)", false),
+            ("\\{", false),
+            ("(?:\\|\\|)", false),
+            ("\\}", false),
+        ];
+        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    }
+}
+pub(crate) use self::__lalrpop_util::lexer::Token;
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action0<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, CompUnit, usize),
+) -> CompUnit
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action1<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, items, _): (usize, alloc::vec::Vec<GlobalItem>, usize),
+) -> CompUnit
+{
+    CompUnit { items }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action2<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, ConstDecl, usize),
+) -> GlobalItem
+{
+    GlobalItem::ConstDecl(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action3<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Decl, usize),
+) -> GlobalItem
+{
+    GlobalItem::Decl(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action4<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, FuncDef, usize),
+) -> GlobalItem
+{
+    GlobalItem::FuncDef(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action5<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, value, _): (usize, i64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Number
+{
+    Number { value, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action6<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    __0.to_string()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action7<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i64
+{
+    __0.parse().unwrap_or(i64::MAX)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action8<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i64
+{
+    u32::from_str_radix(__0, 8).map_or(i64::MAX, |value| value as i32 as i64)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action9<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i64
+{
+    u32::from_str_radix(&__0[2..], 16).map_or(i64::MAX, |value| value as i32 as i64)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action10<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, exp, _): (usize, LOrExp, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp
+{
+    Exp { exp, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action11<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, exp, _): (usize, AddExp, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConstExp
+{
+    ConstExp { exp, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action12<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> PrimaryExp
+{
+    PrimaryExp::Exp(Box::new(__0))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action13<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, LVal, usize),
+) -> PrimaryExp
+{
+    PrimaryExp::LVal(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action14<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Number, usize),
+) -> PrimaryExp
+{
+    PrimaryExp::Number(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action15<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, PrimaryExp, usize),
+) -> UnaryExp
+{
+    UnaryExp::Primary(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action16<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, params, _): (usize, Option<FuncRParams>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> UnaryExp
+{
+    UnaryExp::Func(id, params, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action17<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, op, _): (usize, UnaryOp, usize),
+    (_, exp, _): (usize, UnaryExp, usize),
+) -> UnaryExp
+{
+    UnaryExp::Unary(op, Box::new(exp))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action18<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, UnaryExp, usize),
+) -> MulExp
+{
+    MulExp::Unary(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action19<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, lhs, _): (usize, MulExp, usize),
+    (_, op, _): (usize, MulOp, usize),
+    (_, rhs, _): (usize, UnaryExp, usize),
+    (_, r, _): (usize, usize, usize),
+) -> MulExp
+{
+    MulExp::MulUnary(Box::new(lhs), op, rhs, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action20<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, MulExp, usize),
+) -> AddExp
+{
+    AddExp::Mul(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action21<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, lhs, _): (usize, AddExp, usize),
+    (_, op, _): (usize, AddOp, usize),
+    (_, rhs, _): (usize, MulExp, usize),
+) -> AddExp
+{
+    AddExp::AddMul(Box::new(lhs), op, rhs)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action22<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, AddExp, usize),
+) -> RelExp
+{
+    RelExp::Add(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action23<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, lhs, _): (usize, RelExp, usize),
+    (_, op, _): (usize, RelOp, usize),
+    (_, rhs, _): (usize, AddExp, usize),
+) -> RelExp
+{
+    RelExp::RelAdd(Box::new(lhs), op, rhs)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action24<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, RelExp, usize),
+) -> EqExp
+{
+    EqExp::Rel(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action25<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, lhs, _): (usize, EqExp, usize),
+    (_, op, _): (usize, EqOp, usize),
+    (_, rhs, _): (usize, RelExp, usize),
+) -> EqExp
+{
+    EqExp::EqRel(Box::new(lhs), op, rhs)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action26<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, EqExp, usize),
+) -> LAndExp
+{
+    LAndExp::Eq(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action27<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, lhs, _): (usize, LAndExp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, rhs, _): (usize, EqExp, usize),
+) -> LAndExp
+{
+    LAndExp::LAndEq(Box::new(lhs), rhs)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action28<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, LAndExp, usize),
+) -> LOrExp
+{
+    LOrExp::LAnd(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action29<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, lhs, _): (usize, LOrExp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, rhs, _): (usize, LAndExp, usize),
+) -> LOrExp
+{
+    LOrExp::LOrLAnd(Box::new(lhs), rhs)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action30<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOp
+{
+    UnaryOp::Plus
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action31<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOp
+{
+    UnaryOp::Neg
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action32<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOp
+{
+    UnaryOp::Not
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action33<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> MulOp
+{
+    MulOp::Mul
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action34<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> MulOp
+{
+    MulOp::Div
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action35<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> MulOp
+{
+    MulOp::Mod
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action36<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> AddOp
+{
+    AddOp::Add
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action37<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> AddOp
+{
+    AddOp::Sub
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action38<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> RelOp
+{
+    RelOp::Lt
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action39<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> RelOp
+{
+    RelOp::Gt
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action40<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> RelOp
+{
+    RelOp::Le
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action41<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> RelOp
+{
+    RelOp::Ge
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action42<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> EqOp
+{
+    EqOp::Eq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action43<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> EqOp
+{
+    EqOp::Ne
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action44<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, decl_type, _): (usize, BType, usize),
+    (_, def, _): (usize, ConstDef, usize),
+    (_, mut defs, _): (usize, alloc::vec::Vec<ConstDef>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConstDecl
+{
+    {
+  defs.insert(0, def);
+  ConstDecl { decl_type, defs, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action45<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, dims, _): (usize, alloc::vec::Vec<ConstExp>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, val, _): (usize, ConstInitVal, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConstDef
+{
+    {
+  ConstDef { id, dims, val, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action46<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, ConstExp, usize),
+) -> ConstInitVal
+{
+    ConstInitVal::Exp(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action47<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, &'input str, usize),
+) -> ConstInitVal
+{
+    ConstInitVal::List(Vec::new())
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action48<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, val, _): (usize, ConstInitVal, usize),
+    (_, mut vals, _): (usize, alloc::vec::Vec<ConstInitVal>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> ConstInitVal
+{
+    {
+    vals.insert(0, val);
+    ConstInitVal::List(vals)
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action49<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, decl_type, _): (usize, BType, usize),
+    (_, def, _): (usize, Def, usize),
+    (_, mut defs, _): (usize, alloc::vec::Vec<Def>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Decl
+{
+    {
+  defs.insert(0, def);
+  Decl { decl_type, defs, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action50<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, dims, _): (usize, alloc::vec::Vec<ConstExp>, usize),
+    (_, val, _): (usize, Option<InitVal>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Def
+{
+    {
+  Def { id, dims, val, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action51<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Exp, usize),
+) -> InitVal
+{
+    InitVal::Exp(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action52<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, &'input str, usize),
+) -> InitVal
+{
+    InitVal::List(Vec::new())
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action53<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, val, _): (usize, InitVal, usize),
+    (_, mut vals, _): (usize, alloc::vec::Vec<InitVal>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> InitVal
+{
+    {
+    vals.insert(0, val);
+    InitVal::List(vals)
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action54<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, indices, _): (usize, alloc::vec::Vec<Exp>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> LVal
+{
+    {
+  LVal { id, indices, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action55<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BType
+{
+    BType::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action56<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, items, _): (usize, Vec<BlockItem>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Block
+{
+    Block { items, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action57<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, items, _): (usize, Vec<BlockItem>, usize),
+    (_, error, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Block
+{
+    {
+    errors.push(error);
+    Block { items, span: Span::new(l, r) }
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action58<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<BlockItem>
+{
+    Vec::new()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action59<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, mut items, _): (usize, Vec<BlockItem>, usize),
+    (_, item, _): (usize, BlockItem, usize),
+) -> Vec<BlockItem>
+{
+    {
+    items.push(item);
+    items
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action60<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, ConstDecl, usize),
+) -> BlockItem
+{
+    BlockItem::ConstDecl(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action61<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Decl, usize),
+) -> BlockItem
+{
+    BlockItem::Decl(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action62<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Stmt, usize),
+) -> BlockItem
+{
+    BlockItem::Stmt(Box::new(__0))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action63<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Stmt, usize),
+) -> Stmt
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action64<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Stmt, usize),
+) -> Stmt
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action65<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Stmt, usize),
+) -> Stmt
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action66<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, then_stmt, _): (usize, Stmt, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, else_stmt, _): (usize, Stmt, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::If(If {
+    cond,
+    then_stmt: Box::new(then_stmt),
+    else_stmt: Some(Box::new(else_stmt)),
+    span: Span::new(l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action67<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Stmt, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::While(While {
+    cond,
+    body: Box::new(body),
+    span: Span::new(l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action68<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, then_stmt, _): (usize, Stmt, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::If(If {
+    cond,
+    then_stmt: Box::new(then_stmt),
+    else_stmt: None,
+    span: Span::new(l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action69<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, then_stmt, _): (usize, Stmt, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, else_stmt, _): (usize, Stmt, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::If(If {
+    cond,
+    then_stmt: Box::new(then_stmt),
+    else_stmt: Some(Box::new(else_stmt)),
+    span: Span::new(l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action70<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Stmt, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::While(While {
+    cond,
+    body: Box::new(body),
+    span: Span::new(l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action71<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, lval, _): (usize, LVal, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, exp, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::Assign(Assign { lval, exp, span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action72<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, exp, _): (usize, Option<Exp>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::Exp(ExpStmt { exp, span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action73<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, block, _): (usize, Block, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::Sblock(Sblock { block, span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action74<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::Break(Break { span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action75<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::Continue(Continue { span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action76<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, exp, _): (usize, Option<Exp>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt::Return(Return { exp, span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action77<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, error, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    {
+    errors.push(error);
+    Stmt::Exp(ExpStmt { exp: None, span: Span::new(l, r) })
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action78<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, error, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    (_, block, _): (usize, Block, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    {
+    errors.push(error);
+    Stmt::Sblock(Sblock { block, span: Span::new(l, r) })
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action79<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, error, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    (_, block, _): (usize, Block, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Stmt
+{
+    {
+    errors.push(error);
+    Stmt::Sblock(Sblock { block, span: Span::new(l, r) })
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action80<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, func_type, _): (usize, FuncType, usize),
+    (_, ident, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, params, _): (usize, Option<FuncFParams>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, block, _): (usize, Option<Block>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> FuncDef
+{
+    {
+  FuncDef { func_type, ident, params, block, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action81<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Block, usize),
+) -> Option<Block>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action82<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Option<Block>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action83<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> FuncType
+{
+    FuncType::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action84<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> FuncType
+{
+    FuncType::Void
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action85<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, param, _): (usize, FuncFParam, usize),
+    (_, mut params, _): (usize, alloc::vec::Vec<FuncFParam>, usize),
+) -> FuncFParams
+{
+    {
+  params.insert(0, param);
+  FuncFParams { params }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action86<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, param_type, _): (usize, BType, usize),
+    (_, id, _): (usize, String, usize),
+    (_, dims, _): (usize, Option<alloc::vec::Vec<ConstExp>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> FuncFParam
+{
+    {
+  FuncFParam { param_type, id, dims, span: Span::new(l, r) }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action87<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, param, _): (usize, Exp, usize),
+    (_, mut params, _): (usize, alloc::vec::Vec<Exp>, usize),
+) -> FuncRParams
+{
+    {
+  params.insert(0, param);
+  FuncRParams { params }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action88<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Exp>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action89<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Exp>, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action90<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Exp, usize),
+) -> Exp
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action91<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> Option<alloc::vec::Vec<ConstExp>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action92<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<alloc::vec::Vec<ConstExp>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action93<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> alloc::vec::Vec<ConstExp>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action94<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<FuncFParam>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action95<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<FuncFParam>, usize),
+) -> alloc::vec::Vec<FuncFParam>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action96<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, FuncFParam, usize),
+) -> FuncFParam
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action97<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, FuncFParams, usize),
+) -> Option<FuncFParams>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action98<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<FuncFParams>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action99<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, FuncFParams, usize),
+) -> FuncFParams
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action100<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Exp, usize),
+) -> Option<Exp>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action101<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<Exp>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action102<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Exp, usize),
+) -> Exp
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action103<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Exp>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action104<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Exp>, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action105<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Exp, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Exp
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action106<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<InitVal>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action107<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<InitVal>, usize),
+) -> alloc::vec::Vec<InitVal>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action108<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, InitVal, usize),
+) -> InitVal
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action109<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, InitVal, usize),
+) -> Option<InitVal>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action110<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<InitVal>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action111<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, InitVal, usize),
+) -> InitVal
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action112<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Def>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action113<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Def>, usize),
+) -> alloc::vec::Vec<Def>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action114<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Def, usize),
+) -> Def
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action115<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<ConstInitVal>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action116<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<ConstInitVal>, usize),
+) -> alloc::vec::Vec<ConstInitVal>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action117<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, ConstInitVal, usize),
+) -> ConstInitVal
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action118<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<ConstExp>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action119<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> alloc::vec::Vec<ConstExp>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action120<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, ConstExp, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> ConstExp
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action121<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<ConstDef>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action122<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<ConstDef>, usize),
+) -> alloc::vec::Vec<ConstDef>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action123<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, ConstDef, usize),
+) -> ConstDef
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action124<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, FuncRParams, usize),
+) -> Option<FuncRParams>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action125<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<FuncRParams>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action126<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, FuncRParams, usize),
+) -> FuncRParams
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action127<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    *__lookbehind
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action128<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    *__lookahead
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action129<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, GlobalItem, usize),
+) -> alloc::vec::Vec<GlobalItem>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action130<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<GlobalItem>, usize),
+    (_, e, _): (usize, GlobalItem, usize),
+) -> alloc::vec::Vec<GlobalItem>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action131<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, GlobalItem, usize),
+) -> GlobalItem
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action132<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, ConstDef, usize),
+) -> alloc::vec::Vec<ConstDef>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action133<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<ConstDef>, usize),
+    (_, e, _): (usize, ConstDef, usize),
+) -> alloc::vec::Vec<ConstDef>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action134<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, ConstExp, usize),
+) -> alloc::vec::Vec<ConstExp>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action135<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<ConstExp>, usize),
+    (_, e, _): (usize, ConstExp, usize),
+) -> alloc::vec::Vec<ConstExp>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action136<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, ConstInitVal, usize),
+) -> alloc::vec::Vec<ConstInitVal>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action137<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<ConstInitVal>, usize),
+    (_, e, _): (usize, ConstInitVal, usize),
+) -> alloc::vec::Vec<ConstInitVal>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action138<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Def, usize),
+) -> alloc::vec::Vec<Def>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action139<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Def>, usize),
+    (_, e, _): (usize, Def, usize),
+) -> alloc::vec::Vec<Def>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action140<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, InitVal, usize),
+) -> alloc::vec::Vec<InitVal>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action141<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<InitVal>, usize),
+    (_, e, _): (usize, InitVal, usize),
+) -> alloc::vec::Vec<InitVal>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action142<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Exp, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action143<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Exp>, usize),
+    (_, e, _): (usize, Exp, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action144<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, FuncFParam, usize),
+) -> alloc::vec::Vec<FuncFParam>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action145<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<FuncFParam>, usize),
+    (_, e, _): (usize, FuncFParam, usize),
+) -> alloc::vec::Vec<FuncFParam>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action146<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Exp, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action147<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Exp>, usize),
+    (_, e, _): (usize, Exp, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action148<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, ConstDef, usize),
+) -> alloc::vec::Vec<ConstDef>
+{
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action123(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action132(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action149<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<ConstDef>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ConstDef, usize),
+) -> alloc::vec::Vec<ConstDef>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action123(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action150<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, BType, usize),
+    __3: (usize, ConstDef, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> ConstDecl
+{
+    let __start0 = __3.2;
+    let __end0 = __4.0;
+    let __temp0 = __action121(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action151<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, BType, usize),
+    __3: (usize, ConstDef, usize),
+    __4: (usize, alloc::vec::Vec<ConstDef>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, usize, usize),
+) -> ConstDecl
+{
+    let __start0 = __4.0;
+    let __end0 = __4.2;
+    let __temp0 = __action122(
+        errors,
+        input,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action152<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, ConstInitVal, usize),
+) -> alloc::vec::Vec<ConstInitVal>
+{
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action117(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action153<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<ConstInitVal>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ConstInitVal, usize),
+) -> alloc::vec::Vec<ConstInitVal>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action117(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action137(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action154<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, ConstInitVal, usize),
+    __2: (usize, &'input str, usize),
+) -> ConstInitVal
+{
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action115(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action155<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, ConstInitVal, usize),
+    __2: (usize, alloc::vec::Vec<ConstInitVal>, usize),
+    __3: (usize, &'input str, usize),
+) -> ConstInitVal
+{
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action116(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action156<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Def, usize),
+) -> alloc::vec::Vec<Def>
+{
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action114(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action157<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<Def>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Def, usize),
+) -> alloc::vec::Vec<Def>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action114(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action139(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action158<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, Def, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Decl
+{
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action112(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action159<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, Def, usize),
+    __3: (usize, alloc::vec::Vec<Def>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Decl
+{
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
+        errors,
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action160<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Exp, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action90(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action146(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action161<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<Exp>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+) -> alloc::vec::Vec<Exp>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action90(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action147(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action162<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Exp, usize),
+) -> FuncRParams
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action88(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action163<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Exp, usize),
+    __1: (usize, alloc::vec::Vec<Exp>, usize),
+) -> FuncRParams
+{
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action89(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action164<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, FuncFParam, usize),
+) -> alloc::vec::Vec<FuncFParam>
+{
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action96(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action144(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action165<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<FuncFParam>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, FuncFParam, usize),
+) -> alloc::vec::Vec<FuncFParam>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action96(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action145(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action166<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, FuncFParam, usize),
+) -> FuncFParams
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action94(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action85(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action167<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, FuncFParam, usize),
+    __1: (usize, alloc::vec::Vec<FuncFParam>, usize),
+) -> FuncFParams
+{
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action95(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action85(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action168<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, InitVal, usize),
+) -> alloc::vec::Vec<InitVal>
+{
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action108(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action169<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<InitVal>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, InitVal, usize),
+) -> alloc::vec::Vec<InitVal>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action108(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action0<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action170<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, CompUnit, usize),
-) -> CompUnit
+    __0: (usize, &'input str, usize),
+    __1: (usize, InitVal, usize),
+    __2: (usize, &'input str, usize),
+) -> InitVal
 {
-    __0
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action106(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action1<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action171<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, func_defs, _): (usize, alloc::vec::Vec<FuncDef>, usize),
-) -> CompUnit
+    __0: (usize, &'input str, usize),
+    __1: (usize, InitVal, usize),
+    __2: (usize, alloc::vec::Vec<InitVal>, usize),
+    __3: (usize, &'input str, usize),
+) -> InitVal
+{
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action107(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action172<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, InitVal, usize),
+) -> Option<InitVal>
 {
-    CompUnit { func_defs }
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action111(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action2<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action173<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, InitVal, usize),
+    __5: (usize, usize, usize),
+) -> Def
+{
+    let __start0 = __3.0;
+    let __end0 = __4.2;
+    let __temp0 = __action172(
+        errors,
+        input,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action174<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __3: (usize, usize, usize),
+) -> Def
+{
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action110(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action175<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<ConstExp>
+{
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action118(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action93(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action176<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> alloc::vec::Vec<ConstExp>
+{
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action119(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action93(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action177<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ConstInitVal, usize),
+    __4: (usize, usize, usize),
+) -> ConstDef
+{
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action118(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action178<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, ConstInitVal, usize),
+    __5: (usize, usize, usize),
+) -> ConstDef
+{
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action119(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action179<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, num, _): (usize, i32, usize),
-) -> i32
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, InitVal, usize),
+    __4: (usize, usize, usize),
+) -> Def
 {
-    num
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action118(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action173(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action3<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action180<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> String
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, InitVal, usize),
+    __5: (usize, usize, usize),
+) -> Def
 {
-    __0.to_string()
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action119(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action173(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action4<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action181<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> i32
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+) -> Def
 {
-    i32::from_str_radix(__0, 10).unwrap()
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action118(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action5<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action182<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> i32
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __3: (usize, usize, usize),
+) -> Def
 {
-    i32::from_str_radix(__0, 8).unwrap()
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action119(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action6<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action183<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> i32
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Option<alloc::vec::Vec<ConstExp>>
 {
-    i32::from_str_radix(&__0[2..], 16).unwrap()
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action175(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action91(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action7<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action184<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, exp, _): (usize, LOrExp, usize),
-) -> Exp
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> Option<alloc::vec::Vec<ConstExp>>
 {
-    Exp { exp }
+    let __start0 = __0.0;
+    let __end0 = __2.2;
+    let __temp0 = __action176(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action91(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action8<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action185<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Exp, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> PrimaryExp
+    __0: (usize, usize, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> FuncFParam
 {
-    PrimaryExp::Exp(Box::new(__0))
+    let __start0 = __3.0;
+    let __end0 = __4.2;
+    let __temp0 = __action183(
+        errors,
+        input,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action86(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action9<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action186<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, LVal, usize),
-) -> PrimaryExp
+    __0: (usize, usize, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __6: (usize, usize, usize),
+) -> FuncFParam
 {
-    PrimaryExp::LVal(__0)
+    let __start0 = __3.0;
+    let __end0 = __5.2;
+    let __temp0 = __action184(
+        errors,
+        input,
+        __3,
+        __4,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action86(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action10<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action187<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, i32, usize),
-) -> PrimaryExp
+    __0: (usize, usize, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, String, usize),
+    __3: (usize, usize, usize),
+) -> FuncFParam
 {
-    PrimaryExp::Number(__0)
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action92(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action86(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action11<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action188<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, PrimaryExp, usize),
-) -> UnaryExp
+    __0: (usize, &'input str, usize),
+    __1: (usize, ConstExp, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<ConstExp>
 {
-    UnaryExp::Primary(__0)
+    let __start0 = __0.0;
+    let __end0 = __2.2;
+    let __temp0 = __action120(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action12<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action189<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, params, _): (usize, Option<FuncRParams>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> UnaryExp
+    __0: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ConstExp, usize),
+    __3: (usize, &'input str, usize),
+) -> alloc::vec::Vec<ConstExp>
 {
-    UnaryExp::Func(id, params)
+    let __start0 = __1.0;
+    let __end0 = __3.2;
+    let __temp0 = __action120(
+        errors,
+        input,
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action135(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action13<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action190<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, op, _): (usize, UnaryOp, usize),
-    (_, exp, _): (usize, UnaryExp, usize),
-) -> UnaryExp
+    __0: (usize, &'input str, usize),
+    __1: (usize, Exp, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Exp>
 {
-    UnaryExp::Unary(op, Box::new(exp))
+    let __start0 = __0.0;
+    let __end0 = __2.2;
+    let __temp0 = __action105(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action142(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action14<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action191<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, UnaryExp, usize),
-) -> MulExp
+    __0: (usize, alloc::vec::Vec<Exp>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Exp>
 {
-    MulExp::Unary(__0)
+    let __start0 = __1.0;
+    let __end0 = __3.2;
+    let __temp0 = __action105(
+        errors,
+        input,
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action143(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action15<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action192<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lhs, _): (usize, MulExp, usize),
-    (_, op, _): (usize, MulOp, usize),
-    (_, rhs, _): (usize, UnaryExp, usize),
-) -> MulExp
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+) -> LVal
 {
-    MulExp::MulUnary(Box::new(lhs), op, rhs)
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action103(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action16<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action193<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, MulExp, usize),
-) -> AddExp
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<Exp>, usize),
+    __3: (usize, usize, usize),
+) -> LVal
 {
-    AddExp::Mul(__0)
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action104(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action17<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action194<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lhs, _): (usize, AddExp, usize),
-    (_, op, _): (usize, AddOp, usize),
-    (_, rhs, _): (usize, MulExp, usize),
-) -> AddExp
+    __0: (usize, Exp, usize),
+) -> Option<Exp>
 {
-    AddExp::AddMul(Box::new(lhs), op, rhs)
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action102(
+        errors,
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action100(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action18<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action195<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, AddExp, usize),
-) -> RelExp
+    __0: (usize, usize, usize),
+    __1: (usize, Exp, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Stmt
 {
-    RelExp::Add(__0)
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action194(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action19<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action196<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lhs, _): (usize, RelExp, usize),
-    (_, op, _): (usize, RelOp, usize),
-    (_, rhs, _): (usize, AddExp, usize),
-) -> RelExp
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Stmt
 {
-    RelExp::RelAdd(Box::new(lhs), op, rhs)
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action101(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action20<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action197<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, RelExp, usize),
-) -> EqExp
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Stmt
 {
-    EqExp::Rel(__0)
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action194(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action21<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action198<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lhs, _): (usize, EqExp, usize),
-    (_, op, _): (usize, EqOp, usize),
-    (_, rhs, _): (usize, RelExp, usize),
-) -> EqExp
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Stmt
 {
-    EqExp::EqRel(Box::new(lhs), op, rhs)
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action101(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action22<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action199<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, EqExp, usize),
-) -> LAndExp
+    __0: (usize, FuncFParams, usize),
+) -> Option<FuncFParams>
 {
-    LAndExp::Eq(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action99(
+        errors,
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action97(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action23<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action200<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lhs, _): (usize, LAndExp, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, rhs, _): (usize, EqExp, usize),
-) -> LAndExp
+    __0: (usize, usize, usize),
+    __1: (usize, FuncType, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, FuncFParams, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Option<Block>, usize),
+    __7: (usize, usize, usize),
+) -> FuncDef
 {
-    LAndExp::LAndEq(Box::new(lhs), rhs)
+    let __start0 = __4.0;
+    let __end0 = __4.2;
+    let __temp0 = __action199(
+        errors,
+        input,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action80(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action24<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action201<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, LAndExp, usize),
-) -> LOrExp
+    __0: (usize, usize, usize),
+    __1: (usize, FuncType, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Option<Block>, usize),
+    __6: (usize, usize, usize),
+) -> FuncDef
 {
-    LOrExp::LAnd(__0)
+    let __start0 = __3.2;
+    let __end0 = __4.0;
+    let __temp0 = __action98(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action80(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action25<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action202<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lhs, _): (usize, LOrExp, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, rhs, _): (usize, LAndExp, usize),
-) -> LOrExp
+    __0: (usize, FuncRParams, usize),
+) -> Option<FuncRParams>
 {
-    LOrExp::LOrLAnd(Box::new(lhs), rhs)
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action126(
+        errors,
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action124(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action26<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action203<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> UnaryOp
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, FuncRParams, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> UnaryExp
 {
-    UnaryOp::Plus
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action202(
+        errors,
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action27<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action204<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> UnaryOp
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> UnaryExp
 {
-    UnaryOp::Neg
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action125(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action28<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action205<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> UnaryOp
+    __0: (usize, GlobalItem, usize),
+) -> alloc::vec::Vec<GlobalItem>
 {
-    UnaryOp::Not
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action131(
+        errors,
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action129(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action29<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action206<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> MulOp
+    __0: (usize, alloc::vec::Vec<GlobalItem>, usize),
+    __1: (usize, GlobalItem, usize),
+) -> alloc::vec::Vec<GlobalItem>
 {
-    MulOp::Mul
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action131(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action30<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action207<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> MulOp
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<BlockItem>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Block
 {
-    MulOp::Div
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action31<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action208<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> MulOp
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<BlockItem>, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Block
 {
-    MulOp::Mod
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action32<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action209<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> AddOp
+    __0: (usize, &'input str, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, ConstDef, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> ConstDecl
 {
-    AddOp::Add
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action150(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action33<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action210<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> AddOp
+    __0: (usize, &'input str, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, ConstDef, usize),
+    __3: (usize, alloc::vec::Vec<ConstDef>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> ConstDecl
 {
-    AddOp::Sub
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action151(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action34<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action211<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> RelOp
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ConstInitVal, usize),
+    __3: (usize, usize, usize),
+) -> ConstDef
 {
-    RelOp::Lt
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action35<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action212<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> RelOp
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ConstInitVal, usize),
+    __4: (usize, usize, usize),
+) -> ConstDef
 {
-    RelOp::Gt
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action178(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action36<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action213<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> RelOp
+    __0: (usize, AddExp, usize),
+    __1: (usize, usize, usize),
+) -> ConstExp
 {
-    RelOp::Le
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action37<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action214<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> RelOp
+    __0: (usize, BType, usize),
+    __1: (usize, Def, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Decl
 {
-    RelOp::Ge
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action158(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action38<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action215<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> EqOp
+    __0: (usize, BType, usize),
+    __1: (usize, Def, usize),
+    __2: (usize, alloc::vec::Vec<Def>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Decl
 {
-    EqOp::Eq
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action159(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action39<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action216<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> EqOp
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, InitVal, usize),
+    __3: (usize, usize, usize),
+) -> Def
 {
-    EqOp::Ne
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action179(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action40<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action217<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, decl_type, _): (usize, BType, usize),
-    (_, def, _): (usize, Def, usize),
-    (_, mut defs, _): (usize, alloc::vec::Vec<Def>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Decl
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, InitVal, usize),
+    __4: (usize, usize, usize),
+) -> Def
 {
-    {
-  defs.insert(0, def);
-  Decl { decl_type, defs }
-}
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action41<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action218<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, id, _): (usize, String, usize),
-    (_, val, _): (usize, Option<InitVal>, usize),
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
 ) -> Def
 {
-    Def { id, val }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action181(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action42<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action219<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, exp, _): (usize, Exp, usize),
-) -> InitVal
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __2: (usize, usize, usize),
+) -> Def
 {
-    InitVal { exp }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action182(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action43<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action220<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, id, _): (usize, String, usize),
-) -> LVal
+    __0: (usize, LOrExp, usize),
+    __1: (usize, usize, usize),
+) -> Exp
 {
-    LVal { id }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action44<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action221<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> BType
+    __0: (usize, FuncType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, FuncFParams, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Option<Block>, usize),
+    __6: (usize, usize, usize),
+) -> FuncDef
 {
-    BType::Int
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action200(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action45<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action222<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, items, _): (usize, alloc::vec::Vec<BlockItem>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Block
+    __0: (usize, FuncType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Option<Block>, usize),
+    __5: (usize, usize, usize),
+) -> FuncDef
 {
-    Block { items }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action201(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action46<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action223<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Decl, usize),
-) -> BlockItem
+    __0: (usize, BType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> FuncFParam
 {
-    BlockItem::Decl(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action185(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action47<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action224<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Stmt, usize),
-) -> BlockItem
+    __0: (usize, BType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __5: (usize, usize, usize),
+) -> FuncFParam
 {
-    BlockItem::Stmt(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action186(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action48<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action225<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lval, _): (usize, LVal, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Stmt
+    __0: (usize, BType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+) -> FuncFParam
 {
-    Stmt::Assign(Assign{ lval, exp })
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action187(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action49<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action226<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, block, _): (usize, Block, usize),
-) -> Stmt
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> LVal
 {
-    Stmt::Sblock(Sblock{ block })
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action192(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action50<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action227<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Exp, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, then_block, _): (usize, Block, usize),
-    (_, else_block, _): (usize, Option<Block>, usize),
-) -> Stmt
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<Exp>, usize),
+    __2: (usize, usize, usize),
+) -> LVal
 {
-    Stmt::If(If{ cond, then_block, else_block })
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action193(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action51<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action228<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Exp, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, block, _): (usize, Block, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Stmt, usize),
+    __7: (usize, usize, usize),
 ) -> Stmt
 {
-    Stmt::While(While{ cond, block })
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action52<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action229<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp, usize),
-    (_, _, _): (usize, &'input str, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, usize, usize),
 ) -> Stmt
 {
-    Stmt::Return(Return{ exp })
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action67(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action53<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action230<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, func_type, _): (usize, FuncType, usize),
-    (_, ident, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, params, _): (usize, Option<FuncFParams>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, block, _): (usize, Block, usize),
-) -> FuncDef
+    __0: (usize, MulExp, usize),
+    __1: (usize, MulOp, usize),
+    __2: (usize, UnaryExp, usize),
+    __3: (usize, usize, usize),
+) -> MulExp
 {
-    {
-  FuncDef { func_type, ident, params, block }
-}
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action54<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action231<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> FuncType
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
+) -> Number
 {
-    FuncType::Int
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action55<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action232<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, param, _): (usize, FuncFParam, usize),
-    (_, mut params, _): (usize, alloc::vec::Vec<FuncFParam>, usize),
-) -> FuncFParams
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, usize, usize),
+) -> Stmt
 {
-    {
-  params.insert(0, param);
-  FuncFParams { params }
-}
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action56<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action233<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, param_type, _): (usize, BType, usize),
-    (_, id, _): (usize, String, usize),
-) -> FuncFParam
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Stmt, usize),
+    __7: (usize, usize, usize),
+) -> Stmt
 {
-    FuncFParam { param_type, id }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action57<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action234<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, param, _): (usize, Exp, usize),
-    (_, mut params, _): (usize, alloc::vec::Vec<Exp>, usize),
-) -> FuncRParams
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, usize, usize),
+) -> Stmt
 {
-    {
-  params.insert(0, param);
-  FuncRParams { params }
-}
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action70(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action58<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action235<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Exp>
+    __0: (usize, LVal, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Stmt
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action71(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action59<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action236<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp>, usize),
-) -> alloc::vec::Vec<Exp>
+    __0: (usize, Exp, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Stmt
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action195(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action60<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action237<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Exp, usize),
-) -> Exp
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Stmt
 {
-    __0
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action196(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action61<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action238<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<FuncFParam>
+    __0: (usize, Block, usize),
+    __1: (usize, usize, usize),
+) -> Stmt
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action73(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action62<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action239<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<FuncFParam>, usize),
-) -> alloc::vec::Vec<FuncFParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Stmt
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action74(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action63<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action240<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, FuncFParam, usize),
-) -> FuncFParam
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Stmt
 {
-    __0
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action75(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action64<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action241<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncFParams, usize),
-) -> Option<FuncFParams>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Exp, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Stmt
 {
-    Some(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action197(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action65<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action242<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Option<FuncFParams>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Stmt
 {
-    None
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action198(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action66<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action243<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncFParams, usize),
-) -> FuncFParams
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Stmt
 {
-    __0
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action77(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action67<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action244<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Block, usize),
-) -> Option<Block>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __3: (usize, Block, usize),
+    __4: (usize, usize, usize),
+) -> Stmt
 {
-    Some(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action78(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action68<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action245<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Option<Block>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __3: (usize, Block, usize),
+    __4: (usize, usize, usize),
+) -> Stmt
 {
-    None
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action79(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action69<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action246<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Block, usize),
-) -> Block
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, FuncRParams, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> UnaryExp
 {
-    __0
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action203(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action70<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action247<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<BlockItem>
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> UnaryExp
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action128(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action204(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action71<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action248<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<BlockItem>, usize),
-) -> alloc::vec::Vec<BlockItem>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<BlockItem>, usize),
+    __2: (usize, &'input str, usize),
+) -> Block
 {
-    v
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action207(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action72<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action249<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, BlockItem, usize),
-) -> BlockItem
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<BlockItem>, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __3: (usize, &'input str, usize),
+) -> Block
 {
-    __0
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action208(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action73<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action250<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, InitVal, usize),
-) -> Option<InitVal>
+    __0: (usize, &'input str, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, ConstDef, usize),
+    __3: (usize, &'input str, usize),
+) -> ConstDecl
 {
-    Some(__0)
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action209(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action74<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action251<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Option<InitVal>
+    __0: (usize, &'input str, usize),
+    __1: (usize, BType, usize),
+    __2: (usize, ConstDef, usize),
+    __3: (usize, alloc::vec::Vec<ConstDef>, usize),
+    __4: (usize, &'input str, usize),
+) -> ConstDecl
 {
-    None
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action210(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action75<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action252<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, InitVal, usize),
-) -> InitVal
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ConstInitVal, usize),
+) -> ConstDef
 {
-    __0
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action76<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action253<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Def>
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ConstInitVal, usize),
+) -> ConstDef
 {
-    alloc::vec![]
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action77<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action254<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Def>, usize),
-) -> alloc::vec::Vec<Def>
+    __0: (usize, AddExp, usize),
+) -> ConstExp
 {
-    v
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action78<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action255<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Def, usize),
-) -> Def
+    __0: (usize, BType, usize),
+    __1: (usize, Def, usize),
+    __2: (usize, &'input str, usize),
+) -> Decl
 {
-    __0
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action79<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action256<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncRParams, usize),
-) -> Option<FuncRParams>
+    __0: (usize, BType, usize),
+    __1: (usize, Def, usize),
+    __2: (usize, alloc::vec::Vec<Def>, usize),
+    __3: (usize, &'input str, usize),
+) -> Decl
 {
-    Some(__0)
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action215(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action80<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action257<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Option<FuncRParams>
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, InitVal, usize),
+) -> Def
 {
-    None
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action216(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action81<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action258<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncRParams, usize),
-) -> FuncRParams
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<ConstExp>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, InitVal, usize),
+) -> Def
 {
-    __0
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action217(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action82<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action259<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncDef, usize),
-) -> alloc::vec::Vec<FuncDef>
+    __0: (usize, String, usize),
+) -> Def
 {
-    alloc::vec![__0]
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action218(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action83<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action260<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<FuncDef>, usize),
-    (_, e, _): (usize, FuncDef, usize),
-) -> alloc::vec::Vec<FuncDef>
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> Def
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action219(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action84<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action261<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncDef, usize),
-) -> FuncDef
+    __0: (usize, LOrExp, usize),
+) -> Exp
 {
-    __0
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action220(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action85<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action262<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Def, usize),
-) -> alloc::vec::Vec<Def>
+    __0: (usize, FuncType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, FuncFParams, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Option<Block>, usize),
+) -> FuncDef
 {
-    alloc::vec![__0]
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action221(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action86<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action263<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Def>, usize),
-    (_, e, _): (usize, Def, usize),
-) -> alloc::vec::Vec<Def>
+    __0: (usize, FuncType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Option<Block>, usize),
+) -> FuncDef
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action222(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action87<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action264<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, BlockItem, usize),
-) -> alloc::vec::Vec<BlockItem>
+    __0: (usize, BType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+) -> FuncFParam
 {
-    alloc::vec![__0]
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action223(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action88<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action265<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<BlockItem>, usize),
-    (_, e, _): (usize, BlockItem, usize),
-) -> alloc::vec::Vec<BlockItem>
+    __0: (usize, BType, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> FuncFParam
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action224(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action89<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action266<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FuncFParam, usize),
-) -> alloc::vec::Vec<FuncFParam>
+    __0: (usize, BType, usize),
+    __1: (usize, String, usize),
+) -> FuncFParam
 {
-    alloc::vec![__0]
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action225(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action90<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action267<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<FuncFParam>, usize),
-    (_, e, _): (usize, FuncFParam, usize),
-) -> alloc::vec::Vec<FuncFParam>
+    __0: (usize, String, usize),
+) -> LVal
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action226(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action91<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action268<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Exp, usize),
-) -> alloc::vec::Vec<Exp>
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<Exp>, usize),
+) -> LVal
 {
-    alloc::vec![__0]
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action227(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action92<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action269<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp>, usize),
-    (_, e, _): (usize, Exp, usize),
-) -> alloc::vec::Vec<Exp>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Stmt, usize),
+) -> Stmt
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __6.2;
+    let __end0 = __6.2;
+    let __temp0 = __action127(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action228(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action93<
+fn __action270<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Def, usize),
-) -> alloc::vec::Vec<Def>
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+) -> Stmt
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action78(
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
+    __action229(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
     )
 }
@@ -5413,26 +13553,32 @@ fn __action93<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action94<
+fn __action271<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Def>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Def, usize),
-) -> alloc::vec::Vec<Def>
+    __0: (usize, MulExp, usize),
+    __1: (usize, MulOp, usize),
+    __2: (usize, UnaryExp, usize),
+) -> MulExp
 {
-    let __start0 = __1.0;
+    let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action78(
+    let __temp0 = __action127(
+        errors,
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action86(
+    __action230(
+        errors,
         input,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -5440,82 +13586,106 @@ fn __action94<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action95<
+fn __action272<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, BType, usize),
-    __1: (usize, Def, usize),
-    __2: (usize, &'input str, usize),
-) -> Decl
+    __0: (usize, i64, usize),
+) -> Number
 {
-    let __start0 = __1.2;
-    let __end0 = __2.0;
-    let __temp0 = __action76(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action40(
+    __action231(
+        errors,
         input,
         __0,
-        __1,
         __temp0,
-        __2,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action96<
+fn __action273<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, BType, usize),
-    __1: (usize, Def, usize),
-    __2: (usize, alloc::vec::Vec<Def>, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
     __3: (usize, &'input str, usize),
-) -> Decl
+    __4: (usize, Stmt, usize),
+) -> Stmt
 {
-    let __start0 = __2.0;
-    let __end0 = __2.2;
-    let __temp0 = __action77(
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action40(
+    __action232(
+        errors,
         input,
         __0,
         __1,
-        __temp0,
+        __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action97<
+fn __action274<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Exp, usize),
-) -> alloc::vec::Vec<Exp>
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Stmt, usize),
+) -> Stmt
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action60(
+    let __start0 = __6.2;
+    let __end0 = __6.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action91(
+    __action233(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
         __temp0,
     )
 }
@@ -5523,26 +13693,36 @@ fn __action97<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action98<
+fn __action275<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp>, usize),
+    __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, Exp, usize),
-) -> alloc::vec::Vec<Exp>
+    __3: (usize, &'input str, usize),
+    __4: (usize, Stmt, usize),
+) -> Stmt
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action60(
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action92(
+    __action234(
+        errors,
         input,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
     )
 }
@@ -5550,24 +13730,34 @@ fn __action98<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action99<
+fn __action276<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Exp, usize),
-) -> FuncRParams
+    __0: (usize, LVal, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp, usize),
+    __3: (usize, &'input str, usize),
+) -> Stmt
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action58(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action57(
+    __action235(
+        errors,
         input,
         __0,
+        __1,
+        __2,
+        __3,
         __temp0,
     )
 }
@@ -5575,24 +13765,30 @@ fn __action99<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action100<
+fn __action277<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, Exp, usize),
-    __1: (usize, alloc::vec::Vec<Exp>, usize),
-) -> FuncRParams
+    __1: (usize, &'input str, usize),
+) -> Stmt
 {
-    let __start0 = __1.0;
+    let __start0 = __1.2;
     let __end0 = __1.2;
-    let __temp0 = __action59(
+    let __temp0 = __action127(
+        errors,
         input,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action57(
+    __action236(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
     )
 }
@@ -5600,24 +13796,28 @@ fn __action100<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action101<
+fn __action278<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, FuncFParam, usize),
-) -> alloc::vec::Vec<FuncFParam>
+) -> Stmt
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action63(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action89(
+    __action237(
+        errors,
         input,
+        __0,
         __temp0,
     )
 }
@@ -5625,24 +13825,26 @@ fn __action101<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action102<
+fn __action279<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<FuncFParam>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, FuncFParam, usize),
-) -> alloc::vec::Vec<FuncFParam>
+    __0: (usize, Block, usize),
+) -> Stmt
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action63(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action90(
+    __action238(
+        errors,
         input,
         __0,
         __temp0,
@@ -5652,24 +13854,30 @@ fn __action102<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action103<
+fn __action280<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncFParam, usize),
-) -> FuncFParams
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Stmt
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action61(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action127(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(
+    __action239(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
     )
 }
@@ -5677,24 +13885,30 @@ fn __action103<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action104<
+fn __action281<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncFParam, usize),
-    __1: (usize, alloc::vec::Vec<FuncFParam>, usize),
-) -> FuncFParams
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Stmt
 {
-    let __start0 = __1.0;
+    let __start0 = __1.2;
     let __end0 = __1.2;
-    let __temp0 = __action62(
+    let __temp0 = __action127(
+        errors,
         input,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(
+    __action240(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
     )
 }
@@ -5702,24 +13916,32 @@ fn __action104<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action105<
+fn __action282<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, InitVal, usize),
-) -> Option<InitVal>
+    __1: (usize, Exp, usize),
+    __2: (usize, &'input str, usize),
+) -> Stmt
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action75(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action73(
+    __action241(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -5727,26 +13949,30 @@ fn __action105<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action106<
+fn __action283<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, String, usize),
+    __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, InitVal, usize),
-) -> Def
+) -> Stmt
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action105(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action41(
+    __action242(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
     )
 }
@@ -5754,24 +13980,30 @@ fn __action106<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action107<
+fn __action284<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, String, usize),
-) -> Def
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __1: (usize, &'input str, usize),
+) -> Stmt
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action74(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action127(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action41(
+    __action243(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
     )
 }
@@ -5779,24 +14011,34 @@ fn __action107<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action108<
+fn __action285<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Block, usize),
-) -> Option<Block>
+    __1: (usize, &'input str, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __3: (usize, Block, usize),
+) -> Stmt
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action69(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action67(
+    __action244(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
+        __3,
         __temp0,
     )
 }
@@ -5804,34 +14046,34 @@ fn __action108<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action109<
+fn __action286<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, Exp, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Block, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Block, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __3: (usize, Block, usize),
 ) -> Stmt
 {
-    let __start0 = __5.0;
-    let __end0 = __6.2;
-    let __temp0 = __action108(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __5,
-        __6,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action50(
+    __action245(
+        errors,
         input,
         __0,
         __1,
         __2,
         __3,
-        __4,
         __temp0,
     )
 }
@@ -5839,32 +14081,34 @@ fn __action109<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action110<
+fn __action287<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, String, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, Exp, usize),
+    __2: (usize, FuncRParams, usize),
     __3: (usize, &'input str, usize),
-    __4: (usize, Block, usize),
-) -> Stmt
+) -> UnaryExp
 {
-    let __start0 = __4.2;
-    let __end0 = __4.2;
-    let __temp0 = __action68(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action127(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action50(
+    __action246(
+        errors,
         input,
         __0,
         __1,
         __2,
         __3,
-        __4,
         __temp0,
     )
 }
@@ -5872,22 +14116,32 @@ fn __action110<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action111<
+fn __action288<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, BlockItem, usize),
-) -> alloc::vec::Vec<BlockItem>
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> UnaryExp
 {
-    let __start0 = __0.0;
-    let __end0 = __0.2;
-    let __temp0 = __action72(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action127(
+        errors,
         input,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action87(
+    __action247(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -5895,78 +14149,98 @@ fn __action111<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action112<
+fn __action289<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<BlockItem>, usize),
-    __1: (usize, BlockItem, usize),
-) -> alloc::vec::Vec<BlockItem>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ConstDef, usize),
+    __3: (usize, &'input str, usize),
+) -> ConstDecl
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action72(
+    let __temp0 = __action55(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action88(
+    __action250(
+        errors,
         input,
         __0,
         __temp0,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action113<
+fn __action290<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
-) -> Block
+    __2: (usize, ConstDef, usize),
+    __3: (usize, alloc::vec::Vec<ConstDef>, usize),
+    __4: (usize, &'input str, usize),
+) -> ConstDecl
 {
-    let __start0 = __0.2;
-    let __end0 = __1.0;
-    let __temp0 = __action70(
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action55(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action45(
+    __action251(
+        errors,
         input,
         __0,
         __temp0,
-        __1,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action114<
+fn __action291<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, alloc::vec::Vec<BlockItem>, usize),
+    __1: (usize, Def, usize),
     __2: (usize, &'input str, usize),
-) -> Block
+) -> Decl
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action71(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action55(
+        errors,
         input,
-        __1,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action45(
+    __action255(
+        errors,
         input,
-        __0,
         __temp0,
+        __1,
         __2,
     )
 }
@@ -5974,234 +14248,290 @@ fn __action114<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action115<
+fn __action292<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncDef, usize),
-) -> alloc::vec::Vec<FuncDef>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Def, usize),
+    __2: (usize, alloc::vec::Vec<Def>, usize),
+    __3: (usize, &'input str, usize),
+) -> Decl
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action84(
+    let __temp0 = __action55(
+        errors,
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action82(
+    __action256(
+        errors,
         input,
         __temp0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action116<
+fn __action293<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<FuncDef>, usize),
-    __1: (usize, FuncDef, usize),
-) -> alloc::vec::Vec<FuncDef>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+) -> FuncFParam
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action84(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action55(
+        errors,
         input,
-        __1,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action83(
+    __action264(
+        errors,
         input,
-        __0,
         __temp0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action117<
+fn __action294<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncFParams, usize),
-) -> Option<FuncFParams>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, alloc::vec::Vec<ConstExp>, usize),
+) -> FuncFParam
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action66(
+    let __temp0 = __action55(
+        errors,
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action64(
+    __action265(
+        errors,
         input,
         __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action118<
+fn __action295<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncType, usize),
+    __0: (usize, &'input str, usize),
     __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, FuncFParams, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Block, usize),
-) -> FuncDef
+) -> FuncFParam
 {
-    let __start0 = __3.0;
-    let __end0 = __3.2;
-    let __temp0 = __action117(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action55(
+        errors,
         input,
-        __3,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(
+    __action266(
+        errors,
         input,
-        __0,
-        __1,
-        __2,
         __temp0,
-        __4,
-        __5,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action119<
+fn __action296<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncType, usize),
+    __0: (usize, &'input str, usize),
     __1: (usize, String, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Block, usize),
+    __3: (usize, FuncFParams, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Option<Block>, usize),
 ) -> FuncDef
 {
-    let __start0 = __2.2;
-    let __end0 = __3.0;
-    let __temp0 = __action65(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action83(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(
+    __action262(
+        errors,
         input,
-        __0,
+        __temp0,
         __1,
         __2,
-        __temp0,
         __3,
         __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action120<
+fn __action297<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FuncRParams, usize),
-) -> Option<FuncRParams>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, FuncFParams, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Option<Block>, usize),
+) -> FuncDef
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action81(
+    let __temp0 = __action84(
+        errors,
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action79(
+    __action262(
+        errors,
         input,
         __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action121<
+fn __action298<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, FuncRParams, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
     __3: (usize, &'input str, usize),
-) -> UnaryExp
+    __4: (usize, Option<Block>, usize),
+) -> FuncDef
 {
-    let __start0 = __2.0;
-    let __end0 = __2.2;
-    let __temp0 = __action120(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action83(
+        errors,
         input,
-        __2,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
+    __action263(
+        errors,
         input,
-        __0,
-        __1,
         __temp0,
+        __1,
+        __2,
         __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action122<
+fn __action299<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
     __2: (usize, &'input str, usize),
-) -> UnaryExp
+    __3: (usize, &'input str, usize),
+    __4: (usize, Option<Block>, usize),
+) -> FuncDef
 {
-    let __start0 = __1.2;
-    let __end0 = __2.0;
-    let __temp0 = __action80(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action84(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
+    __action263(
+        errors,
         input,
-        __0,
-        __1,
         __temp0,
+        __1,
         __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(clippy::type_complexity, dead_code)]
-pub  trait __ToTriple<'input, >
+pub  trait __ToTriple<'input, 'err, >
 {
     fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<'input, 'err, > __ToTriple<'input, 'err, > for (usize, Token<'input>, usize)
 {
     fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
         Ok(self)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
+impl<'input, 'err, > __ToTriple<'input, 'err, > for Result<(usize, Token<'input>, usize), &'static str>
 {
     fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
         self.map_err(|error| __lalrpop_util::ParseError::User { error })
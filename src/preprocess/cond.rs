@@ -0,0 +1,224 @@
+use crate::preprocess::macros::{ident_len, token_len, Macros};
+use crate::source::Origin;
+
+/// A conditional group opened by `#if`, `#ifdef` or `#ifndef`.
+pub struct Cond {
+    /// Whether the lines of the current branch are kept.
+    pub active: bool,
+    /// Whether some branch has been kept, so the later ones are skipped.
+    pub taken: bool,
+    /// Whether the lines around the group are kept.
+    pub parent: bool,
+    pub seen_else: bool,
+    /// Where the group is opened.
+    pub origin: Origin,
+}
+
+/// Evaluates the condition of `#if` or `#elif`. As in C, `defined` is replaced first,
+/// then macros are expanded, and the identifiers left are 0.
+pub fn evaluate(expr: &str, macros: &Macros, line: usize) -> Result<bool, String> {
+    let expr = macros.expand(&replace_defined(expr, macros)?, line).map_err(|err| err.to_string())?;
+    let tokens = tokenize(&expr)?;
+    if tokens.is_empty() {
+        return Err("#if with no expression".into());
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let value = parser.ternary()?;
+    match parser.peek() {
+        None => Ok(value != 0),
+        Some(token) => Err(format!("unexpected `{}` in #if", token)),
+    }
+}
+
+fn replace_defined(expr: &str, macros: &Macros) -> Result<String, String> {
+    let mut out = String::new();
+    let mut rest = expr;
+    while !rest.is_empty() {
+        let (token, after) = rest.split_at(token_len(rest));
+        rest = after;
+        if token != "defined" {
+            out.push_str(token);
+            continue;
+        }
+        let operand = rest.trim_start();
+        let (parenthesized, operand) = match operand.strip_prefix('(') {
+            Some(operand) => (true, operand.trim_start()),
+            None => (false, operand),
+        };
+        let (name, after) = operand.split_at(ident_len(operand));
+        if name.is_empty() {
+            return Err("operator `defined` requires an identifier".into());
+        }
+        rest = match parenthesized {
+            true => after.trim_start().strip_prefix(')').ok_or("missing `)` after `defined`")?,
+            false => after,
+        };
+        out.push_str(if macros.is_defined(name) { "1" } else { "0" });
+    }
+    Ok(out)
+}
+
+enum Token {
+    Number(i64),
+    Punct(&'static str),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Punct(punct) => write!(f, "{}", punct),
+        }
+    }
+}
+
+/// Punctuators in `#if`, longer ones first.
+const PUNCTS: [&str; 27] = [
+    "||", "&&", "==", "!=", "<=", ">=", "<<", ">>",
+    "+", "-", "*", "/", "%", "<", ">", "!", "~", "&", "|", "^", "(", ")", "?", ":", ",", "[", "]",
+];
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = expr.trim_start();
+    while !rest.is_empty() {
+        let len = token_len(rest);
+        let token = &rest[..len];
+        if token.starts_with(|c: char| c.is_ascii_digit()) {
+            tokens.push(Token::Number(parse_number(token)?));
+        } else if ident_len(token) > 0 {
+            tokens.push(Token::Number(0));
+        } else {
+            let punct = PUNCTS
+                .iter()
+                .find(|punct| rest.starts_with(*punct))
+                .ok_or_else(|| format!("invalid token `{}` in #if", token))?;
+            tokens.push(Token::Punct(punct));
+            rest = &rest[punct.len()..];
+            rest = rest.trim_start();
+            continue;
+        }
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+fn parse_number(token: &str) -> Result<i64, String> {
+    let digits = token.trim_end_matches(['u', 'U', 'l', 'L']);
+    let value = if let Some(hex) = digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8)
+    } else {
+        digits.parse()
+    };
+    value.map_err(|_| format!("invalid integer `{}` in #if", token))
+}
+
+/// Binary operators from the lowest precedence to the highest.
+const BINARY: [&[&str]; 10] = [
+    &["||"], &["&&"], &["|"], &["^"], &["&"], &["==", "!="], &["<", ">", "<=", ">="], &["<<", ">>"], &["+", "-"],
+    &["*", "/", "%"],
+];
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn punct(&self) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Punct(punct)) => Some(punct),
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.punct() {
+            Some(punct) if punct == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(format!("expected `{}` in #if", expected)),
+        }
+    }
+
+    fn ternary(&mut self) -> Result<i64, String> {
+        let cond = self.binary(0)?;
+        if self.punct() != Some("?") {
+            return Ok(cond);
+        }
+        self.pos += 1;
+        let then_value = self.ternary()?;
+        self.expect(":")?;
+        let else_value = self.ternary()?;
+        Ok(if cond != 0 { then_value } else { else_value })
+    }
+
+    fn binary(&mut self, level: usize) -> Result<i64, String> {
+        if level == BINARY.len() {
+            return self.unary();
+        }
+        let mut lhs = self.binary(level + 1)?;
+        while let Some(op) = self.punct().filter(|op| BINARY[level].contains(op)) {
+            self.pos += 1;
+            let rhs = self.binary(level + 1)?;
+            lhs = match op {
+                "||" => (lhs != 0 || rhs != 0) as i64,
+                "&&" => (lhs != 0 && rhs != 0) as i64,
+                "|" => lhs | rhs,
+                "^" => lhs ^ rhs,
+                "&" => lhs & rhs,
+                "==" => (lhs == rhs) as i64,
+                "!=" => (lhs != rhs) as i64,
+                "<" => (lhs < rhs) as i64,
+                ">" => (lhs > rhs) as i64,
+                "<=" => (lhs <= rhs) as i64,
+                ">=" => (lhs >= rhs) as i64,
+                "<<" => lhs.wrapping_shl(rhs as u32),
+                ">>" => lhs.wrapping_shr(rhs as u32),
+                "+" => lhs.wrapping_add(rhs),
+                "-" => lhs.wrapping_sub(rhs),
+                "*" => lhs.wrapping_mul(rhs),
+                _ if rhs == 0 => return Err("division by zero in #if".into()),
+                "/" => lhs.wrapping_div(rhs),
+                _ => lhs.wrapping_rem(rhs),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<i64, String> {
+        match self.peek() {
+            Some(Token::Number(number)) => {
+                let number = *number;
+                self.pos += 1;
+                Ok(number)
+            }
+            Some(Token::Punct("(")) => {
+                self.pos += 1;
+                let value = self.ternary()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Some(Token::Punct(op @ ("!" | "-" | "+" | "~"))) => {
+                let op = *op;
+                self.pos += 1;
+                let value = self.unary()?;
+                Ok(match op {
+                    "!" => (value == 0) as i64,
+                    "-" => value.wrapping_neg(),
+                    "~" => !value,
+                    _ => value,
+                })
+            }
+            Some(token) => Err(format!("unexpected `{}` in #if", token)),
+            None => Err("unexpected end of #if expression".into()),
+        }
+    }
+}
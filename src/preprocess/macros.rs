@@ -0,0 +1,214 @@
+use crate::source::Span;
+use std::collections::HashMap;
+use std::fmt;
+
+struct Macro {
+    /// `Some` for function-like macros.
+    params: Option<Vec<String>>,
+    body: String,
+}
+
+pub struct ExpandError {
+    pub kind: ExpandErrorKind,
+    /// The invocation in the expanded text which is at fault, or whose expansion the fault is in.
+    pub span: Span,
+}
+
+pub enum ExpandErrorKind {
+    /// The arguments of an invocation of the macro go on past the end of the text.
+    Unterminated(String),
+    ArgumentCountMismatch { name: String, expected: usize, found: usize },
+}
+
+impl ExpandErrorKind {
+    fn at(self, span: Span) -> ExpandError {
+        ExpandError { kind: self, span }
+    }
+}
+
+impl fmt::Display for ExpandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ExpandErrorKind::Unterminated(name) => write!(f, "unterminated argument list invoking macro `{}`", name),
+            ExpandErrorKind::ArgumentCountMismatch { name, expected, found } => write!(
+                f, "macro `{}` takes {} argument(s) but {} were given", name, expected, found,
+            ),
+        }
+    }
+}
+
+/// The macros defined so far, `__LINE__` is always defined.
+#[derive(Default)]
+pub struct Macros(HashMap<String, Macro>);
+
+impl Macros {
+    /// Defines the macro from the text after `#define`.
+    pub fn define(&mut self, definition: &str) -> Result<(), String> {
+        let len = ident_len(definition);
+        if len == 0 {
+            return Err("macro names must be identifiers".into());
+        }
+        let (name, rest) = definition.split_at(len);
+        if name == "__LINE__" {
+            return Err("`__LINE__` cannot be redefined".into());
+        }
+        // a parameter list must follow the name immediately
+        let (params, body) = match rest.strip_prefix('(') {
+            Some(rest) => {
+                let (params, body) = rest
+                    .split_once(')')
+                    .ok_or("missing `)` in macro parameter list")?;
+                let params = match params.trim() {
+                    "" => Vec::new(),
+                    params => params
+                        .split(',')
+                        .map(|param| match param.trim() {
+                            param if ident_len(param) == param.len() && !param.is_empty() => Ok(param.to_string()),
+                            _ => Err("expected parameter name in macro parameter list"),
+                        })
+                        .collect::<Result<_, _>>()?,
+                };
+                (Some(params), body)
+            }
+            None => (None, rest),
+        };
+        self.0.insert(name.into(), Macro { params, body: body.trim().into() });
+        Ok(())
+    }
+
+    pub fn undef(&mut self, name: &str) {
+        self.0.remove(name);
+    }
+
+    pub fn is_defined(&self, name: &str) -> bool {
+        name == "__LINE__" || self.0.contains_key(name)
+    }
+
+    /// Replaces the macros in a line of code, `line` is what `__LINE__` expands to.
+    pub fn expand(&self, text: &str, line: usize) -> Result<String, ExpandError> {
+        self.expand_with(text, line, &mut Vec::new())
+    }
+
+    /// Expands `text` where the `disabled` macros are being expanded already,
+    /// so that they are not expanded again inside themselves.
+    fn expand_with(&self, text: &str, line: usize, disabled: &mut Vec<String>) -> Result<String, ExpandError> {
+        let mut out = String::new();
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let start = text.len() - rest.len();
+            let len = token_len(rest);
+            let (token, after) = rest.split_at(len);
+            rest = after;
+            if !(c.is_ascii_alphabetic() || c == '_') {
+                out.push_str(token);
+                continue;
+            }
+            let expansion = match self.0.get(token) {
+                _ if token == "__LINE__" => line.to_string(),
+                Some(Macro { params: None, body }) if !disabled.iter().any(|name| name == token) => {
+                    disabled.push(token.into());
+                    let expansion = self.expand_with(body, line, disabled);
+                    disabled.pop();
+                    // errors in the body are reported at the invocation
+                    expansion.map_err(|err| err.kind.at(Span::new(start, start + len)))?
+                }
+                Some(Macro { params: Some(params), body }) if !disabled.iter().any(|name| name == token) => {
+                    // the name of a function-like macro alone is not an invocation
+                    let Some(args) = rest.trim_start().strip_prefix('(') else {
+                        out.push_str(token);
+                        continue;
+                    };
+                    let (args, after) = split_args(args)
+                        .ok_or_else(|| ExpandErrorKind::Unterminated(token.into()).at(Span::new(start, start + len)))?;
+                    rest = after;
+                    let span = Span::new(start, text.len() - rest.len());
+                    let args = match args.as_slice() {
+                        [arg] if params.is_empty() && arg.trim().is_empty() => Vec::new(),
+                        _ => args,
+                    };
+                    if args.len() != params.len() {
+                        let (name, expected, found) = (token.into(), params.len(), args.len());
+                        return Err(ExpandErrorKind::ArgumentCountMismatch { name, expected, found }.at(span));
+                    }
+                    // arguments are expanded before they are substituted, errors in them and in the body
+                    // are reported at the invocation
+                    let args = args
+                        .into_iter()
+                        .map(|arg| self.expand_with(arg.trim(), line, disabled))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|err| err.kind.at(span))?;
+                    let body = substitute(body, params, &args);
+                    disabled.push(token.into());
+                    let expansion = self.expand_with(&body, line, disabled);
+                    disabled.pop();
+                    expansion.map_err(|err| err.kind.at(span))?
+                }
+                _ => token.into(),
+            };
+            out.push_str(&expansion);
+        }
+        Ok(out)
+    }
+}
+
+/// Replaces the parameters in the body of a function-like macro by the arguments.
+fn substitute(body: &str, params: &[String], args: &[String]) -> String {
+    let mut out = String::new();
+    let mut rest = body;
+    while !rest.is_empty() {
+        let (token, after) = rest.split_at(token_len(rest));
+        rest = after;
+        match params.iter().position(|param| param == token) {
+            Some(i) => out.push_str(&args[i]),
+            None => out.push_str(token),
+        }
+    }
+    out
+}
+
+/// Splits the arguments of a macro invocation after its `(`, returns them
+/// with the text after the closing `)`.
+fn split_args(text: &str) -> Option<(Vec<&str>, &str)> {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                args.push(&text[start..i]);
+                return Some((args, &text[i + 1..]));
+            }
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the length of the identifier at the start of `text`, or 0 if there is none.
+pub fn ident_len(text: &str) -> usize {
+    match text.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => text
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(text.len()),
+        _ => 0,
+    }
+}
+
+/// Returns the length of the token at the start of the non-empty `text`.
+/// Numbers are taken as a whole, so that `0x1F` does not contain an identifier.
+pub fn token_len(text: &str) -> usize {
+    match text.chars().next() {
+        Some(c) if c.is_ascii_digit() => text
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(text.len()),
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => ident_len(text),
+        Some(c) => c.len_utf8(),
+        None => 0,
+    }
+}
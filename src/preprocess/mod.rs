@@ -0,0 +1,265 @@
+mod cond;
+mod macros;
+
+use crate::preprocess::cond::{evaluate, Cond};
+use crate::preprocess::macros::{ident_len, ExpandErrorKind, Macros};
+use crate::source::{Origin, SourceFile};
+use std::fs::read_to_string;
+use std::ops::Range;
+use std::path::Path;
+
+/// The header of the runtime library, used when there is no `sylib.h` next to the source.
+const SYLIB_H: &str = "\
+#ifndef __SYLIB_H_
+#define __SYLIB_H_
+#define starttime() _sysy_starttime(__LINE__)
+#define stoptime() _sysy_stoptime(__LINE__)
+#endif
+";
+
+/// Limits `#include` nesting, so that a header including itself is reported.
+const MAX_INCLUDE_DEPTH: usize = 200;
+
+pub struct Error {
+    pub origin: Origin,
+    /// The columns of the line the error is about, starting from 1, or `None` for the whole line.
+    pub columns: Option<Range<usize>>,
+    pub message: String,
+}
+
+pub struct Preprocessed {
+    /// The text to parse, with comments and directives blanked out and macros expanded.
+    pub text: String,
+    /// The origin of each line of `text`.
+    pub origins: Vec<Origin>,
+    pub files: Vec<SourceFile>,
+    pub errors: Vec<Error>,
+}
+
+/// Runs the preprocessor on the source file at `path` with the content `text`.
+pub fn preprocess(path: &Path, text: String) -> Preprocessed {
+    let mut preprocessor = Preprocessor {
+        macros: Macros::default(),
+        conds: Vec::new(),
+        depth: 0,
+        out: Preprocessed {
+            text: String::new(),
+            origins: Vec::new(),
+            files: vec![SourceFile { name: path.display().to_string(), text }],
+            errors: Vec::new(),
+        },
+    };
+    preprocessor.file(0, path.parent().unwrap_or(Path::new("")));
+    preprocessor.out
+}
+
+struct Preprocessor {
+    macros: Macros,
+    /// The enclosing conditional groups, innermost last.
+    conds: Vec<Cond>,
+    /// How many `#include`s the current file is nested in.
+    depth: usize,
+    out: Preprocessed,
+}
+
+impl Preprocessor {
+    /// Processes a file, headers it includes are looked up in `dir`.
+    fn file(&mut self, file: usize, dir: &Path) {
+        let text = self.out.files[file].text.clone();
+        let lines: Vec<_> = text.lines().collect();
+        // conditional groups cannot span files
+        let base = self.conds.len();
+        let mut in_comment = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let origin = Origin { file, line: i + 1 };
+            let mut line = logical_line(&lines, &mut i, &mut in_comment);
+            if let Some(directive) = line.trim_start().strip_prefix('#') {
+                self.directive(directive, origin, dir, base);
+                self.emit(String::new(), origin);
+            } else if self.active() {
+                // the arguments of a macro invocation may span lines, which are then expanded
+                // together as the first one
+                let expanded = loop {
+                    match self.macros.expand(&line, origin.line) {
+                        Err(err)
+                            if matches!(err.kind, ExpandErrorKind::Unterminated(_))
+                                && i < lines.len()
+                                && !lines[i].trim_start().starts_with('#') =>
+                        {
+                            line.push(' ');
+                            line.push_str(&logical_line(&lines, &mut i, &mut in_comment));
+                        }
+                        expanded => break expanded,
+                    }
+                };
+                match expanded {
+                    Ok(line) => self.emit(line, origin),
+                    Err(err) => {
+                        // columns are only known within the first line, as comments are replaced by spaces
+                        let end = lines[origin.line - 1].chars().count() + 1;
+                        let column = |offset: usize| line[..offset].chars().count() + 1;
+                        let columns = Some(column(err.span.start)..column(err.span.end).min(end))
+                            .filter(|columns| columns.start < end);
+                        self.out.errors.push(Error { origin, columns, message: err.to_string() });
+                        self.emit(line, origin);
+                    }
+                }
+            } else {
+                self.emit(String::new(), origin);
+            }
+        }
+        while self.conds.len() > base {
+            let cond = self.conds.pop().unwrap();
+            self.error(cond.origin, "unterminated conditional directive".into());
+        }
+    }
+
+    fn directive(&mut self, directive: &str, origin: Origin, dir: &Path, base: usize) {
+        let directive = directive.trim_start();
+        let (name, rest) = directive.split_at(ident_len(directive));
+        let rest = rest.trim();
+        let parent = self.active();
+        match name {
+            "ifdef" | "ifndef" => {
+                let active = match ident_len(rest) {
+                    len if len > 0 && len == rest.len() => parent && self.macros.is_defined(rest) == (name == "ifdef"),
+                    _ => {
+                        self.error(origin, format!("#{} expects a macro name", name));
+                        false
+                    }
+                };
+                self.conds.push(Cond { active, taken: active, parent, seen_else: false, origin });
+            }
+            "if" => {
+                let active = parent && self.condition(rest, origin);
+                self.conds.push(Cond { active, taken: active, parent, seen_else: false, origin });
+            }
+            "elif" | "else" => {
+                let Some(cond) = self.conds.last().filter(|_| self.conds.len() > base) else {
+                    return self.error(origin, format!("#{} without #if", name));
+                };
+                if cond.seen_else {
+                    return self.error(origin, format!("#{} after #else", name));
+                }
+                let enter = cond.parent && !cond.taken;
+                let active = match name {
+                    "elif" => enter && self.condition(rest, origin),
+                    _ => enter,
+                };
+                let cond = self.conds.last_mut().unwrap();
+                cond.active = active;
+                cond.taken |= active;
+                cond.seen_else = name == "else";
+            }
+            "endif" => {
+                if self.conds.len() == base {
+                    return self.error(origin, "#endif without #if".into());
+                }
+                self.conds.pop();
+            }
+            // the other directives are skipped with the code around them
+            _ if !parent => {}
+            "define" => {
+                if let Err(message) = self.macros.define(rest) {
+                    self.error(origin, message);
+                }
+            }
+            "undef" => match ident_len(rest) {
+                len if len > 0 && len == rest.len() => self.macros.undef(rest),
+                _ => self.error(origin, "#undef expects a macro name".into()),
+            },
+            "include" => self.include(rest, origin, dir),
+            "pragma" => {}
+            "" if rest.is_empty() => {}
+            _ => {
+                let name = directive.split_whitespace().next().unwrap_or(directive);
+                self.error(origin, format!("invalid preprocessing directive `#{}`", name));
+            }
+        }
+    }
+
+    /// Evaluates the condition of `#if` or `#elif`, errors make it false.
+    fn condition(&mut self, expr: &str, origin: Origin) -> bool {
+        evaluate(expr, &self.macros, origin.line).unwrap_or_else(|message| {
+            self.error(origin, message);
+            false
+        })
+    }
+
+    fn include(&mut self, operand: &str, origin: Origin, dir: &Path) {
+        let name = operand
+            .strip_prefix('"')
+            .and_then(|name| name.strip_suffix('"'))
+            .or_else(|| operand.strip_prefix('<')?.strip_suffix('>'));
+        let Some(name) = name else {
+            return self.error(origin, "#include expects \"FILENAME\" or <FILENAME>".into());
+        };
+        if self.depth == MAX_INCLUDE_DEPTH {
+            return self.error(origin, "#include nested too deeply".into());
+        }
+        let path = dir.join(name);
+        let text = match read_to_string(&path) {
+            Ok(text) => text,
+            Err(_) if name == "sylib.h" => SYLIB_H.into(),
+            Err(err) => return self.error(origin, format!("cannot include `{}`: {}", name, err)),
+        };
+        self.out.files.push(SourceFile { name: path.display().to_string(), text });
+        self.depth += 1;
+        self.file(self.out.files.len() - 1, path.parent().unwrap_or(dir));
+        self.depth -= 1;
+    }
+
+    fn active(&self) -> bool {
+        self.conds.last().is_none_or(|cond| cond.active)
+    }
+
+    fn emit(&mut self, line: String, origin: Origin) {
+        self.out.text.push_str(&line);
+        self.out.text.push('\n');
+        self.out.origins.push(origin);
+    }
+
+    fn error(&mut self, origin: Origin, message: String) {
+        self.out.errors.push(Error { origin, columns: None, message });
+    }
+}
+
+/// Reads the line at `i` and those spliced to it by a trailing backslash, advancing `i` past
+/// them, and strips the comments.
+fn logical_line(lines: &[&str], i: &mut usize, in_comment: &mut bool) -> String {
+    let mut line = lines[*i].to_string();
+    *i += 1;
+    while line.ends_with('\\') && *i < lines.len() {
+        line.pop();
+        line.push_str(lines[*i]);
+        *i += 1;
+    }
+    strip_comments(&line, in_comment)
+}
+
+/// Replaces comments by spaces so that columns are kept, `in_comment` tells whether
+/// the line starts inside a block comment and is updated for the next line.
+fn strip_comments(line: &str, in_comment: &mut bool) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if *in_comment {
+            if c == '*' && chars.peek() == Some(&'/') {
+                chars.next();
+                *in_comment = false;
+                out.push(' ');
+            }
+            out.push(' ');
+        } else if c == '/' && chars.peek() == Some(&'*') {
+            chars.next();
+            *in_comment = true;
+            out.push_str("  ");
+        } else if c == '/' && chars.peek() == Some(&'/') {
+            break;
+        } else {
+            out.push(c);
+        }
+    }
+    out.trim_end().into()
+}
@@ -0,0 +1,279 @@
+use crate::sema::{Analysis, Error, ErrorKind, Result};
+use crate::sema::symbol::{Symbol, SymbolId, SymbolKind, Type};
+use crate::sema::warning::{Flow, Warning, WarningKind};
+use crate::source::Span;
+use std::collections::{HashMap, HashSet};
+
+/// Functions provided by the SysY runtime library.
+fn library() -> Vec<(&'static str, Vec<Type>, Type)> {
+    let int_ptr = Type::Pointer(Vec::new());
+    vec![
+        ("getint", vec![], Type::Int),
+        ("getch", vec![], Type::Int),
+        ("getarray", vec![int_ptr.clone()], Type::Int),
+        ("putint", vec![Type::Int], Type::Void),
+        ("putch", vec![Type::Int], Type::Void),
+        ("putarray", vec![Type::Int, int_ptr], Type::Void),
+        ("starttime", vec![], Type::Void),
+        ("stoptime", vec![], Type::Void),
+        // what `starttime()` and `stoptime()` expand to in sylib.h
+        ("_sysy_starttime", vec![Type::Int], Type::Void),
+        ("_sysy_stoptime", vec![Type::Int], Type::Void),
+    ]
+}
+
+/// Points at an earlier symbol of the same name, which is only declared if it is a prototype.
+fn previous_note(symbol: &Symbol) -> (Span, &'static str) {
+    let note = match symbol.kind {
+        SymbolKind::Function { defined: false, .. } => "previously declared here",
+        _ => "previously defined here",
+    };
+    (symbol.span.unwrap(), note)
+}
+
+pub struct Analyzer<'ast> {
+    pub analysis: Analysis,
+    /// Symbols visible in each scope, the outermost one holds the runtime
+    /// library so that the program can define the same names.
+    scopes: Vec<HashMap<&'ast str, SymbolId>>,
+    /// The return type of the function being analyzed.
+    ret_type: Option<Type>,
+    /// The joined states of the `break`s in each enclosing loop.
+    breaks: Vec<Flow>,
+    /// The state of the dataflow at the node being analyzed.
+    pub flow: Flow,
+    used: HashSet<SymbolId>,
+    params: HashSet<SymbolId>,
+    errors: Vec<Error>,
+    warnings: Vec<Warning>,
+}
+
+impl<'ast> Analyzer<'ast> {
+    pub fn new() -> Self {
+        let mut analyzer = Self {
+            analysis: Analysis::new(),
+            scopes: vec![HashMap::new()],
+            ret_type: None,
+            breaks: Vec::new(),
+            flow: Flow::entry(),
+            used: HashSet::new(),
+            params: HashSet::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        for (name, params, ret) in library() {
+            let id = analyzer.new_symbol(name, None, SymbolKind::Function { params, ret, defined: false });
+            analyzer.scopes[0].insert(name, id);
+        }
+        analyzer.push();
+        analyzer
+    }
+
+    fn new_symbol(&mut self, name: &str, span: Option<Span>, kind: SymbolKind) -> SymbolId {
+        self.analysis.symbols.push(Symbol { name: name.into(), span, kind });
+        SymbolId(self.analysis.symbols.len() - 1)
+    }
+
+    pub fn is_global(&self) -> bool {
+        self.scopes.len() == 2
+    }
+
+    /// Defines the symbol in the current scope, `span` is the definition node.
+    pub fn define(&mut self, name: &'ast str, span: Span, kind: SymbolKind) -> Result<SymbolId> {
+        if let Some(&previous) = self.scopes.last().unwrap().get(name) {
+            return Err(Error::redefinition(name, previous_note(self.analysis.symbol(previous))));
+        }
+        // the prelude is not an outer declaration, the program may reuse its names
+        let outer = self.scopes[1..self.scopes.len() - 1]
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name));
+        if let (false, Some(&outer)) = (self.is_global(), outer) {
+            let outer = self.analysis.symbol(outer).span.unwrap();
+            self.warn(WarningKind::Shadow(name.into()), span, Some((outer, "shadowed declaration is here")));
+        }
+        let id = self.new_symbol(name, Some(span), kind);
+        self.scopes.last_mut().unwrap().insert(name, id);
+        self.analysis.defs.insert(span, id);
+        Ok(id)
+    }
+
+    /// Declares the function in the global scope, or defines the function declared
+    /// by a prototype before, in which case both must have the same signature.
+    pub fn define_function(
+        &mut self,
+        name: &'ast str,
+        span: Span,
+        params: Vec<Type>,
+        ret: Type,
+        defined: bool,
+    ) -> Result<SymbolId> {
+        let Some(&id) = self.scopes.last().unwrap().get(name) else {
+            return self.define(name, span, SymbolKind::Function { params, ret, defined });
+        };
+        let symbol = &mut self.analysis.symbols[id.0];
+        let previous = previous_note(symbol);
+        match &mut symbol.kind {
+            SymbolKind::Function { defined: true, .. } if defined => Err(Error::redefinition(name, previous)),
+            SymbolKind::Function { params: previous_params, ret: previous_ret, defined: previous_defined } => {
+                if *previous_params != params || *previous_ret != ret {
+                    return Err(Error::signature_mismatch(name, previous));
+                }
+                if defined {
+                    *previous_defined = true;
+                    symbol.span = Some(span);
+                }
+                self.analysis.defs.insert(span, id);
+                Ok(id)
+            }
+            _ => Err(Error::redefinition(name, previous)),
+        }
+    }
+
+    /// Resolves the name used at `span` to the innermost symbol visible.
+    /// Functions are visible in the whole file, but objects only after their definitions.
+    pub fn resolve(&mut self, name: &str, span: Span) -> Result<SymbolId> {
+        let symbols = &self.analysis.symbols;
+        let id = self.scopes
+            .iter()
+            .rev()
+            .filter_map(|scope| scope.get(name).copied())
+            .find(|id| match &symbols[id.0] {
+                Symbol { kind: SymbolKind::Function { .. }, .. } => true,
+                Symbol { span: defined, .. } => defined.is_some_and(|defined| defined.start < span.start),
+            })
+            .ok_or_else(|| ErrorKind::SymbolNotFound(name.into()))?;
+        self.analysis.uses.insert(span, id);
+        self.used.insert(id);
+        Ok(id)
+    }
+
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        self.analysis.symbol(id)
+    }
+
+    pub fn ret_type(&self) -> Option<&Type> {
+        self.ret_type.as_ref()
+    }
+
+    pub fn set_ret_type(&mut self, ret_type: Option<Type>) {
+        self.ret_type = ret_type;
+    }
+
+    /// Defines a parameter in the scope of the function body.
+    pub fn define_param(&mut self, name: &'ast str, span: Span, ty: Type) -> Result<SymbolId> {
+        let id = self.define(name, span, SymbolKind::Var { ty, init: None })?;
+        self.params.insert(id);
+        Ok(id)
+    }
+
+    pub fn in_loop(&self) -> bool {
+        !self.breaks.is_empty()
+    }
+
+    pub fn push_loop(&mut self) {
+        self.breaks.push(Flow::unreachable());
+    }
+
+    /// Returns the joined state of the `break`s out of the loop.
+    pub fn pop_loop(&mut self) -> Flow {
+        self.breaks.pop().unwrap()
+    }
+
+    /// Leaves the innermost loop from the current state.
+    pub fn break_loop(&mut self) {
+        let flow = std::mem::replace(&mut self.flow, Flow::unreachable());
+        let breaks = self.breaks.last_mut().unwrap();
+        *breaks = std::mem::replace(breaks, Flow::unreachable()).join(flow);
+    }
+
+    /// Checks that the node at `span` can be reached, reporting only the first node
+    /// of each piece of unreachable code.
+    pub fn reach(&mut self, span: Span) {
+        if let Flow::Unreachable { reported: false } = self.flow {
+            self.flow = Flow::Unreachable { reported: true };
+            self.warn(WarningKind::Unreachable, span, None);
+        }
+    }
+
+    /// Checks that the scalar variable read at `span` has been initialized.
+    pub fn read(&mut self, id: SymbolId, span: Span) {
+        if self.flow.may_be_uninitialized(id) {
+            // reported once, as if it were initialized from now on
+            self.flow.initialize(id);
+            let name = self.symbol(id).name.clone();
+            self.warn(WarningKind::Uninitialized(name), span, None);
+        }
+    }
+
+    pub fn push(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    /// Leaves the scope, reporting the symbols that are never used in it.
+    pub fn pop(&mut self) {
+        let scope = self.scopes.pop().unwrap();
+        for id in scope.into_values() {
+            if self.used.contains(&id) {
+                continue;
+            }
+            let symbol = self.analysis.symbol(id);
+            let kind = match self.params.contains(&id) {
+                true => WarningKind::UnusedParameter(symbol.name.clone()),
+                false => WarningKind::UnusedVariable(symbol.name.clone()),
+            };
+            let span = symbol.span.unwrap();
+            self.warn(kind, span, None);
+        }
+    }
+
+    pub fn warn(&mut self, kind: WarningKind, span: Span, note: Option<(Span, &'static str)>) {
+        self.warnings.push(Warning { kind, span, note });
+    }
+
+    /// Runs `f` with `span` as the location of the errors it reports,
+    /// unless they are already located by an inner node.
+    pub fn located<T>(&mut self, span: Span, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        f(self).map_err(|mut err| {
+            err.span.get_or_insert(span);
+            err
+        })
+    }
+
+    /// Records the error, if any, so that the analysis can go on with the next node.
+    pub fn recover(&mut self, result: Result<()>) {
+        if let Err(err) = result {
+            self.errors.push(err);
+        }
+    }
+
+    /// Checks that `main` is defined properly and returns the analysis, or all the errors found.
+    pub fn finish(mut self) -> std::result::Result<Analysis, Vec<Error>> {
+        match self.scopes[1].get("main").map(|&id| self.analysis.symbol(id)) {
+            Some(Symbol { kind: SymbolKind::Function { params, ret: Type::Int, defined: true }, .. })
+                if params.is_empty() => {}
+            Some(Symbol { kind: SymbolKind::Function { defined: false, .. }, .. }) | None => {
+                self.errors.push(ErrorKind::MissingMain.into())
+            }
+            Some(Symbol { span, .. }) => self.errors.push(Error { span: *span, ..ErrorKind::InvalidMain.into() }),
+        }
+        // bodies are analyzed after all the signatures, so errors are sorted back into source order
+        self.errors.sort_by_key(|err| err.span.map_or(usize::MAX, |span| span.start));
+        self.warnings.sort_by_key(|warning| warning.span.start);
+        self.analysis.warnings = self.warnings;
+        if self.errors.is_empty() {
+            Ok(self.analysis)
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// Checks that a value of the type can be used as an int.
+pub fn expect_int(ty: &Type) -> Result<()> {
+    match ty {
+        Type::Int => Ok(()),
+        Type::Void => Err(ErrorKind::UseVoidValue.into()),
+        ty => Err(ErrorKind::ExpectedInt(ty.clone()).into()),
+    }
+}
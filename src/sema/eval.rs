@@ -0,0 +1,172 @@
+use crate::sema::{Analysis, ErrorKind, Result, SymbolKind, Type};
+use crate::ast::exp::*;
+use crate::ast::var::LVal;
+
+/// Evaluates constant expressions at compile time, names must have been resolved.
+pub trait Evaluate {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32>;
+}
+
+impl Evaluate for Exp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        self.exp.evaluate(analysis)
+    }
+}
+
+impl Evaluate for ConstExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        self.exp.evaluate(analysis)
+    }
+}
+
+impl Evaluate for LVal {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        let (ty, elems) = match &analysis.symbol(analysis.resolve(self.span)).kind {
+            SymbolKind::Const { ty, elems } => (ty, elems),
+            _ => return Err(ErrorKind::NonConstantExpression.into()),
+        };
+        let dims: &[usize] = match ty {
+            Type::Array(dims) => dims,
+            _ => &[],
+        };
+        if self.indices.len() != dims.len() {
+            return Err(ErrorKind::NonConstantExpression.into());
+        }
+        let mut offset = 0;
+        for (index, &dim) in self.indices.iter().zip(dims) {
+            match index.evaluate(analysis)? {
+                index if index >= 0 && (index as usize) < dim => {
+                    offset = offset * dim + index as usize;
+                }
+                _ => return Err(ErrorKind::NonConstantExpression.into()),
+            }
+        }
+        Ok(elems[offset])
+    }
+}
+
+impl Evaluate for PrimaryExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Exp(exp) => exp.evaluate(analysis),
+            Self::LVal(lval) => lval.evaluate(analysis),
+            // checked by the analysis, `2147483648` only appears negated and wraps to itself
+            Self::Number(number) => Ok(number.value as i32),
+        }
+    }
+}
+
+impl Evaluate for UnaryExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Primary(exp) => exp.evaluate(analysis),
+            Self::Func(..) => Err(ErrorKind::NonConstantExpression.into()),
+            Self::Unary(op, exp) => {
+                let exp = exp.evaluate(analysis)?;
+                Ok(match op {
+                    UnaryOp::Plus => exp,
+                    UnaryOp::Neg => exp.wrapping_neg(),
+                    UnaryOp::Not => (exp == 0) as i32,
+                })
+            }
+        }
+    }
+}
+
+impl Evaluate for MulExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Unary(exp) => exp.evaluate(analysis),
+            Self::MulUnary(lhs, op, rhs, _) => {
+                let lhs = lhs.evaluate(analysis)?;
+                let rhs = rhs.evaluate(analysis)?;
+                match op {
+                    MulOp::Mul => Ok(lhs.wrapping_mul(rhs)),
+                    _ if rhs == 0 => Err(ErrorKind::DivisionByZero.into()),
+                    MulOp::Div => Ok(lhs.wrapping_div(rhs)),
+                    MulOp::Mod => Ok(lhs.wrapping_rem(rhs)),
+                }
+            }
+        }
+    }
+}
+
+impl Evaluate for AddExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Mul(exp) => exp.evaluate(analysis),
+            Self::AddMul(lhs, op, rhs) => {
+                let lhs = lhs.evaluate(analysis)?;
+                let rhs = rhs.evaluate(analysis)?;
+                Ok(match op {
+                    AddOp::Add => lhs.wrapping_add(rhs),
+                    AddOp::Sub => lhs.wrapping_sub(rhs),
+                })
+            }
+        }
+    }
+}
+
+impl Evaluate for RelExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Add(exp) => exp.evaluate(analysis),
+            Self::RelAdd(lhs, op, rhs) => {
+                let lhs = lhs.evaluate(analysis)?;
+                let rhs = rhs.evaluate(analysis)?;
+                Ok(match op {
+                    RelOp::Lt => lhs < rhs,
+                    RelOp::Gt => lhs > rhs,
+                    RelOp::Le => lhs <= rhs,
+                    RelOp::Ge => lhs >= rhs,
+                } as i32)
+            }
+        }
+    }
+}
+
+impl Evaluate for EqExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Rel(exp) => exp.evaluate(analysis),
+            Self::EqRel(lhs, op, rhs) => {
+                let lhs = lhs.evaluate(analysis)?;
+                let rhs = rhs.evaluate(analysis)?;
+                Ok(match op {
+                    EqOp::Eq => lhs == rhs,
+                    EqOp::Ne => lhs != rhs,
+                } as i32)
+            }
+        }
+    }
+}
+
+impl Evaluate for LAndExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::Eq(exp) => exp.evaluate(analysis),
+            Self::LAndEq(lhs, rhs) => {
+                if lhs.evaluate(analysis)? == 0 {
+                    Ok(0)
+                } else {
+                    Ok((rhs.evaluate(analysis)? != 0) as i32)
+                }
+            }
+        }
+    }
+}
+
+impl Evaluate for LOrExp {
+    fn evaluate(&self, analysis: &Analysis) -> Result<i32> {
+        match self {
+            Self::LAnd(exp) => exp.evaluate(analysis),
+            Self::LOrLAnd(lhs, rhs) => {
+                if lhs.evaluate(analysis)? != 0 {
+                    Ok(1)
+                } else {
+                    Ok((rhs.evaluate(analysis)? != 0) as i32)
+                }
+            }
+        }
+    }
+}
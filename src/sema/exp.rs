@@ -0,0 +1,191 @@
+use crate::sema::{Analyze, ErrorKind, Evaluate, Result, SymbolKind, Type};
+use crate::sema::analyzer::{expect_int, Analyzer};
+use crate::ast::exp::*;
+use crate::ast::func::FuncRParams;
+use crate::source::Span;
+
+/// The magnitude of `INT_MIN`, the only literal out of range that is allowed.
+const INT_MIN_ABS: i64 = 1 << 31;
+
+impl<'ast> Analyze<'ast> for Exp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        analyzer.located(self.span, |analyzer| self.exp.analyze(analyzer))
+    }
+}
+
+impl Exp {
+    /// Analyzes an expression whose value must be an int.
+    pub fn analyze_int<'ast>(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<()> {
+        analyzer.located(self.span, |analyzer| expect_int(&self.exp.analyze(analyzer)?))
+    }
+}
+
+impl<'ast> Analyze<'ast> for ConstExp {
+    type Out = i32;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        analyzer.located(self.span, |analyzer| {
+            expect_int(&self.exp.analyze(analyzer)?)?;
+            self.evaluate(&analyzer.analysis)
+        })
+    }
+}
+
+impl<'ast> Analyze<'ast> for PrimaryExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Exp(exp) => exp.analyze(analyzer),
+            Self::LVal(lval) => {
+                let ty = lval.analyze(analyzer)?;
+                if lval.indices.is_empty() {
+                    analyzer.read(analyzer.analysis.resolve(lval.span), lval.span);
+                }
+                Ok(ty.decay())
+            }
+            Self::Number(number) => match i32::try_from(number.value) {
+                Ok(_) => Ok(Type::Int),
+                Err(_) => Err(ErrorKind::LiteralOutOfRange.at(number.span)),
+            },
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for UnaryExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Primary(exp) => exp.analyze(analyzer),
+            Self::Func(id, params, span) => {
+                analyzer.located(*span, |analyzer| analyze_call(id, params, *span, analyzer))
+            }
+            // `2147483648` only fits in an `int` once negated, as in C where `-2147483648` is `INT_MIN`
+            Self::Unary(UnaryOp::Neg, exp) if matches!(
+                exp.as_ref(),
+                UnaryExp::Primary(PrimaryExp::Number(Number { value: INT_MIN_ABS, .. })),
+            ) => Ok(Type::Int),
+            Self::Unary(_, exp) => {
+                expect_int(&exp.analyze(analyzer)?)?;
+                Ok(Type::Int)
+            }
+        }
+    }
+}
+
+/// Checks the arguments of a call against the signature of the callee.
+fn analyze_call<'ast>(
+    id: &'ast str,
+    params: &'ast Option<FuncRParams>,
+    span: Span,
+    analyzer: &mut Analyzer<'ast>,
+) -> Result<Type> {
+    let symbol = analyzer.resolve(id, span)?;
+    let (param_types, ret) = match &analyzer.symbol(symbol).kind {
+        SymbolKind::Function { params, ret, .. } => (params.clone(), ret.clone()),
+        _ => return Err(ErrorKind::NotAFunction(id.into()).into()),
+    };
+    let args: Vec<&Exp> = params.iter().flat_map(|params| &params.params).collect();
+    let arg_types = args
+        .iter()
+        .map(|arg| arg.analyze(analyzer))
+        .collect::<Result<Vec<_>>>()?;
+    if param_types.len() != args.len() {
+        return Err(ErrorKind::ArgumentCountMismatch {
+            id: id.into(),
+            expected: param_types.len(),
+            found: args.len(),
+        }.into());
+    }
+    for (index, ((found, expected), arg)) in arg_types.into_iter().zip(param_types).zip(args).enumerate() {
+        if found != expected {
+            return Err(ErrorKind::ArgumentTypeMismatch {
+                id: id.into(),
+                index,
+                expected: Box::new(expected),
+                found: Box::new(found),
+            }.at(arg.span));
+        }
+    }
+    Ok(ret)
+}
+
+/// Checks the operands of a binary operator, which are both ints.
+fn analyze_binary<'ast, L, R>(lhs: &'ast L, rhs: &'ast R, analyzer: &mut Analyzer<'ast>) -> Result<Type>
+where
+    L: Analyze<'ast, Out = Type>,
+    R: Analyze<'ast, Out = Type>,
+{
+    expect_int(&lhs.analyze(analyzer)?)?;
+    expect_int(&rhs.analyze(analyzer)?)?;
+    Ok(Type::Int)
+}
+
+impl<'ast> Analyze<'ast> for MulExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Unary(exp) => exp.analyze(analyzer),
+            Self::MulUnary(lhs, _, rhs, _) => analyze_binary(lhs.as_ref(), rhs, analyzer),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for AddExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Mul(exp) => exp.analyze(analyzer),
+            Self::AddMul(lhs, _, rhs) => analyze_binary(lhs.as_ref(), rhs, analyzer),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for RelExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Add(exp) => exp.analyze(analyzer),
+            Self::RelAdd(lhs, _, rhs) => analyze_binary(lhs.as_ref(), rhs, analyzer),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for EqExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Rel(exp) => exp.analyze(analyzer),
+            Self::EqRel(lhs, _, rhs) => analyze_binary(lhs.as_ref(), rhs, analyzer),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for LAndExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::Eq(exp) => exp.analyze(analyzer),
+            Self::LAndEq(lhs, rhs) => analyze_binary(lhs.as_ref(), rhs, analyzer),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for LOrExp {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Self::LAnd(exp) => exp.analyze(analyzer),
+            Self::LOrLAnd(lhs, rhs) => analyze_binary(lhs.as_ref(), rhs, analyzer),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::sema::{Analyze, Result, Type};
+use crate::sema::warning::Flow;
+use crate::sema::analyzer::Analyzer;
+use crate::sema::var::analyze_dims;
+use crate::ast::*;
+
+impl<'ast> Analyze<'ast> for CompUnit {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        // bodies are analyzed after all the signatures are known,
+        // so that a function can be called before it is defined
+        let mut bodies = Vec::new();
+        for item in &self.items {
+            let result = match item {
+                GlobalItem::ConstDecl(decl) => analyzer.located(decl.span, |analyzer| decl.analyze(analyzer)),
+                GlobalItem::Decl(decl) => analyzer.located(decl.span, |analyzer| decl.analyze(analyzer)),
+                GlobalItem::FuncDef(func_def) => analyzer.located(func_def.span, |analyzer| {
+                    let (params, ret) = func_def.signature(analyzer)?;
+                    let defined = func_def.block.is_some();
+                    let result = analyzer.define_function(&func_def.ident, func_def.span, params.clone(), ret.clone(), defined);
+                    if let Some(block) = &func_def.block {
+                        bodies.push((func_def, block, params, ret));
+                    }
+                    result.map(|_| ())
+                }),
+            };
+            analyzer.recover(result);
+        }
+        for (func_def, block, params, ret) in bodies {
+            let result = analyzer.located(func_def.span, |analyzer| func_def.analyze_body(block, params, ret, analyzer));
+            analyzer.recover(result);
+        }
+        Ok(())
+    }
+}
+
+impl FuncDef {
+    fn params(&self) -> &[FuncFParam] {
+        self.params.as_ref().map_or(&[], |params| &params.params)
+    }
+
+    /// Returns the parameter types and the return type of the function.
+    fn signature<'ast>(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<(Vec<Type>, Type)> {
+        let params = self
+            .params()
+            .iter()
+            .map(|param| analyzer.located(param.span, |analyzer| param.analyze(analyzer)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((params, self.func_type.analyze(analyzer)?))
+    }
+
+    fn analyze_body<'ast>(
+        &'ast self,
+        block: &'ast Block,
+        params: Vec<Type>,
+        ret: Type,
+        analyzer: &mut Analyzer<'ast>,
+    ) -> Result<()> {
+        // parameters live in the same scope as the outermost block of the body
+        analyzer.push();
+        analyzer.flow = Flow::entry();
+        for (param, ty) in self.params().iter().zip(params) {
+            let result = analyzer
+                .located(param.span, |analyzer| analyzer.define_param(&param.id, param.span, ty))
+                .map(|_| ());
+            analyzer.recover(result);
+        }
+        analyzer.set_ret_type(Some(ret));
+        block.analyze_items(analyzer);
+        analyzer.set_ret_type(None);
+        analyzer.pop();
+        Ok(())
+    }
+}
+
+impl<'ast> Analyze<'ast> for FuncType {
+    type Out = Type;
+
+    fn analyze(&'ast self, _: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        Ok(match self {
+            Self::Int => Type::Int,
+            Self::Void => Type::Void,
+        })
+    }
+}
+
+impl<'ast> Analyze<'ast> for FuncFParam {
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let base = self.param_type.analyze(analyzer)?;
+        Ok(match &self.dims {
+            Some(dims) => Type::Pointer(analyze_dims(dims, analyzer)?),
+            None => base,
+        })
+    }
+}
@@ -0,0 +1,181 @@
+mod analyzer;
+mod eval;
+mod exp;
+mod var;
+mod stmt;
+mod func;
+mod symbol;
+mod warning;
+
+pub use eval::Evaluate;
+pub use symbol::{Symbol, SymbolId, SymbolKind, Type};
+pub use var::flatten;
+pub use warning::{Warning, WarningKind, WarningOptions};
+
+use core::fmt;
+use std::collections::HashMap;
+
+use crate::ast::CompUnit;
+use crate::source::Span;
+use analyzer::Analyzer;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Nodes checked by the semantic analysis.
+pub trait Analyze<'ast> {
+    type Out;
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out>;
+}
+
+/// The results of the semantic analysis, which annotate the syntax tree
+/// through the spans of its definitions and name uses.
+pub struct Analysis {
+    symbols: Vec<Symbol>,
+    /// The symbol defined by each definition node.
+    defs: HashMap<Span, SymbolId>,
+    /// The symbol referred to by each lvalue and function call.
+    uses: HashMap<Span, SymbolId>,
+    /// Warnings in source order.
+    warnings: Vec<Warning>,
+}
+
+impl Analysis {
+    fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            defs: HashMap::new(),
+            uses: HashMap::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        &self.symbols[id.0]
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = (SymbolId, &Symbol)> {
+        self.symbols.iter().enumerate().map(|(i, symbol)| (SymbolId(i), symbol))
+    }
+
+    /// Returns the symbol defined by the node at `span`.
+    pub fn def(&self, span: Span) -> SymbolId {
+        self.defs[&span]
+    }
+
+    /// Returns the symbol the name used at `span` refers to.
+    pub fn resolve(&self, span: Span) -> SymbolId {
+        self.uses[&span]
+    }
+
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
+
+/// A semantic error.
+pub struct Error {
+    pub kind: ErrorKind,
+    /// The innermost syntax node being analyzed when the error occurred.
+    pub span: Option<Span>,
+    /// The earlier definition or declaration of the symbol with its explanation, for redefinitions.
+    pub previous: Option<(Span, &'static str)>,
+}
+
+impl Error {
+    pub fn redefinition(id: &str, previous: (Span, &'static str)) -> Self {
+        Self {
+            kind: ErrorKind::DuplicateDefinition(id.into()),
+            span: None,
+            previous: Some(previous),
+        }
+    }
+
+    pub fn signature_mismatch(id: &str, previous: (Span, &'static str)) -> Self {
+        Self {
+            kind: ErrorKind::SignatureMismatch(id.into()),
+            span: None,
+            previous: Some(previous),
+        }
+    }
+}
+
+impl ErrorKind {
+    /// Reports the error at the given span rather than the node being analyzed.
+    pub fn at(self, span: Span) -> Error {
+        Error { kind: self, span: Some(span), previous: None }
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self { kind, span: None, previous: None }
+    }
+}
+
+pub enum ErrorKind {
+    DuplicateDefinition(String),
+    SignatureMismatch(String),
+    SymbolNotFound(String),
+    ReturnInVoidFunction,
+    MissingReturnValue,
+    UseVoidValue,
+    ExpectedInt(Type),
+    AssignToConstant(String),
+    AssignToArray(Type),
+    NonConstantExpression,
+    DivisionByZero,
+    LiteralOutOfRange,
+    InvalidArraySize,
+    InvalidInitializer,
+    InvalidSubscript(String),
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    NotAFunction(String),
+    NotAVariable(String),
+    ArgumentCountMismatch { id: String, expected: usize, found: usize },
+    ArgumentTypeMismatch { id: String, index: usize, expected: Box<Type>, found: Box<Type> },
+    MissingMain,
+    InvalidMain,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DuplicateDefinition(id) => write!(f, "redefinition of `{}`", id),
+            Self::SignatureMismatch(id) => write!(f, "conflicting signatures for `{}`", id),
+            Self::SymbolNotFound(id) => write!(f, "`{}` is not defined", id),
+            Self::ReturnInVoidFunction => write!(f, "return with a value in void function"),
+            Self::MissingReturnValue => write!(f, "return without a value in function returning int"),
+            Self::UseVoidValue => write!(f, "void value is used"),
+            Self::ExpectedInt(ty) => write!(f, "expected `int`, found `{}`", ty),
+            Self::AssignToConstant(id) => write!(f, "assignment to constant `{}`", id),
+            Self::AssignToArray(ty) => write!(f, "array type `{}` is not assignable", ty),
+            Self::NonConstantExpression => write!(f, "non-constant expression"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::LiteralOutOfRange => write!(f, "integer literal is too large for `int`"),
+            Self::InvalidArraySize => write!(f, "invalid array size"),
+            Self::InvalidInitializer => write!(f, "invalid initializer"),
+            Self::InvalidSubscript(id) => write!(f, "invalid subscript of `{}`", id),
+            Self::BreakOutsideLoop => write!(f, "break statement not within loop"),
+            Self::ContinueOutsideLoop => write!(f, "continue statement not within loop"),
+            Self::NotAFunction(id) => write!(f, "called object `{}` is not a function", id),
+            Self::NotAVariable(id) => write!(f, "function `{}` is used as a variable", id),
+            Self::ArgumentCountMismatch { id, expected, found } => write!(
+                f, "function `{}` takes {} argument(s) but {} were given", id, expected, found,
+            ),
+            Self::ArgumentTypeMismatch { id, index, expected, found } => write!(
+                f, "argument {} of `{}` has type `{}`, expected `{}`", index + 1, id, found, expected,
+            ),
+            Self::MissingMain => write!(f, "no `main` function is defined"),
+            Self::InvalidMain => write!(f, "`main` must be defined as `int main()`"),
+        }
+    }
+}
+
+/// Resolves names, checks types and evaluates constants of the program.
+pub fn analyze(comp_unit: &CompUnit) -> std::result::Result<Analysis, Vec<Error>> {
+    let mut analyzer = Analyzer::new();
+    let result = comp_unit.analyze(&mut analyzer);
+    analyzer.recover(result);
+    analyzer.finish()
+}
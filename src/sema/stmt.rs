@@ -0,0 +1,209 @@
+use crate::sema::{Analyze, ErrorKind, Evaluate, Result, SymbolKind, Type};
+use crate::sema::analyzer::Analyzer;
+use crate::sema::warning::Flow;
+use crate::source::Span;
+use crate::ast::stmt::*;
+
+impl<'ast> Analyze<'ast> for Block {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        analyzer.located(self.span, |analyzer| {
+            analyzer.push();
+            self.analyze_items(analyzer);
+            analyzer.pop();
+            Ok(())
+        })
+    }
+}
+
+impl Block {
+    /// Analyzes the items in the current scope, reporting the errors of each one.
+    pub fn analyze_items<'ast>(&'ast self, analyzer: &mut Analyzer<'ast>) {
+        for item in &self.items {
+            analyzer.reach(item.span());
+            let result = item.analyze(analyzer);
+            analyzer.recover(result);
+        }
+    }
+}
+
+impl BlockItem {
+    fn span(&self) -> Span {
+        match self {
+            BlockItem::ConstDecl(decl) => decl.span,
+            BlockItem::Decl(decl) => decl.span,
+            BlockItem::Stmt(stmt) => match stmt.as_ref() {
+                Stmt::Assign(assign) => assign.span,
+                Stmt::Exp(exp) => exp.span,
+                Stmt::Sblock(sblock) => sblock.span,
+                Stmt::If(sif) => sif.span,
+                Stmt::While(swhile) => swhile.span,
+                Stmt::Break(sbreak) => sbreak.span,
+                Stmt::Continue(scontinue) => scontinue.span,
+                Stmt::Return(ret) => ret.span,
+            },
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for BlockItem {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            BlockItem::Stmt(stmt) => stmt.analyze(analyzer),
+            BlockItem::ConstDecl(decl) => analyzer.located(decl.span, |analyzer| decl.analyze(analyzer)),
+            BlockItem::Decl(decl) => analyzer.located(decl.span, |analyzer| decl.analyze(analyzer)),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for Stmt {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match self {
+            Stmt::Assign(assign) => analyzer.located(assign.span, |analyzer| assign.analyze(analyzer)),
+            Stmt::Exp(exp) => analyzer.located(exp.span, |analyzer| exp.analyze(analyzer)),
+            Stmt::Sblock(sblock) => analyzer.located(sblock.span, |analyzer| sblock.analyze(analyzer)),
+            Stmt::If(sif) => analyzer.located(sif.span, |analyzer| sif.analyze(analyzer)),
+            Stmt::While(swhile) => analyzer.located(swhile.span, |analyzer| swhile.analyze(analyzer)),
+            Stmt::Break(sbreak) => analyzer.located(sbreak.span, |analyzer| sbreak.analyze(analyzer)),
+            Stmt::Continue(scontinue) => analyzer.located(scontinue.span, |analyzer| scontinue.analyze(analyzer)),
+            Stmt::Return(ret) => analyzer.located(ret.span, |analyzer| ret.analyze(analyzer)),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for Assign {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let ty = self.lval.analyze(analyzer)?;
+        let symbol = analyzer.analysis.resolve(self.lval.span);
+        if !matches!(analyzer.symbol(symbol).kind, SymbolKind::Var { .. }) {
+            return Err(ErrorKind::AssignToConstant(self.lval.id.clone()).into());
+        }
+        if ty != Type::Int {
+            return Err(ErrorKind::AssignToArray(ty).into());
+        }
+        self.exp.analyze_int(analyzer)?;
+        if self.lval.indices.is_empty() {
+            analyzer.flow.initialize(symbol);
+        }
+        Ok(())
+    }
+}
+
+impl<'ast> Analyze<'ast> for ExpStmt {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        // the value is discarded, so calls to void functions are allowed here
+        if let Some(exp) = &self.exp {
+            exp.analyze(analyzer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'ast> Analyze<'ast> for Sblock {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        self.block.analyze(analyzer)
+    }
+}
+
+/// Analyzes the body of a compound statement, which has its own scope.
+fn analyze_body<'ast>(stmt: &'ast Stmt, analyzer: &mut Analyzer<'ast>) -> Result<()> {
+    analyzer.push();
+    let result = stmt.analyze(analyzer);
+    analyzer.pop();
+    result
+}
+
+impl<'ast> Analyze<'ast> for If {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let result = self.cond.analyze_int(analyzer);
+        analyzer.recover(result);
+        let entry = analyzer.flow.clone();
+        let result = analyze_body(&self.then_stmt, analyzer);
+        analyzer.recover(result);
+        let then_flow = std::mem::replace(&mut analyzer.flow, entry);
+        let result = match &self.else_stmt {
+            Some(else_stmt) => analyze_body(else_stmt, analyzer),
+            None => Ok(()),
+        };
+        analyzer.flow = std::mem::replace(&mut analyzer.flow, Flow::unreachable()).join(then_flow);
+        result
+    }
+}
+
+impl<'ast> Analyze<'ast> for While {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let cond = self.cond.analyze_int(analyzer);
+        // the loop is left only by `break` if the condition is always true
+        let forever = cond.is_ok() && self.cond.evaluate(&analyzer.analysis).is_ok_and(|cond| cond != 0);
+        analyzer.recover(cond);
+        // the body only initializes variables, so the state of the condition
+        // on later iterations is no worse than on the first one
+        let entry = analyzer.flow.clone();
+        analyzer.push_loop();
+        let result = analyze_body(&self.body, analyzer);
+        let breaks = analyzer.pop_loop();
+        analyzer.flow = match forever {
+            true => breaks,
+            false => entry.join(breaks),
+        };
+        result
+    }
+}
+
+impl<'ast> Analyze<'ast> for Break {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match analyzer.in_loop() {
+            true => {
+                analyzer.break_loop();
+                Ok(())
+            }
+            false => Err(ErrorKind::BreakOutsideLoop.into()),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for Continue {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        match analyzer.in_loop() {
+            true => {
+                analyzer.flow = Flow::unreachable();
+                Ok(())
+            }
+            false => Err(ErrorKind::ContinueOutsideLoop.into()),
+        }
+    }
+}
+
+impl<'ast> Analyze<'ast> for Return {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let result = match (&self.exp, analyzer.ret_type()) {
+            (Some(exp), Some(Type::Int)) => exp.analyze_int(analyzer),
+            (None, Some(Type::Int)) => Err(ErrorKind::MissingReturnValue.into()),
+            (Some(_), _) => Err(ErrorKind::ReturnInVoidFunction.into()),
+            (None, _) => Ok(()),
+        };
+        analyzer.flow = Flow::unreachable();
+        result
+    }
+}
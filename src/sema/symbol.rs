@@ -0,0 +1,78 @@
+use crate::source::Span;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SymbolId(pub(super) usize);
+
+pub struct Symbol {
+    pub name: String,
+    /// Where the symbol is defined, `None` for the runtime library.
+    pub span: Option<Span>,
+    pub kind: SymbolKind,
+}
+
+pub enum SymbolKind {
+    /// A constant with its row-major elements, a scalar has exactly one.
+    Const { ty: Type, elems: Vec<i32> },
+    /// A variable, global ones also hold the elements of their initializer.
+    Var { ty: Type, init: Option<Vec<i32>> },
+    /// A function, which is `defined` once its body is seen rather than just its prototype.
+    Function { params: Vec<Type>, ret: Type, defined: bool },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    Void,
+    Int,
+    /// An array of ints with the given dimensions.
+    Array(Vec<usize>),
+    /// A pointer to arrays of the given dimensions, or to ints if there are none.
+    /// Array parameters and arrays used as values have this type.
+    Pointer(Vec<usize>),
+}
+
+impl Type {
+    /// Returns the type of an object with the given dimensions.
+    pub fn object(dims: Vec<usize>) -> Self {
+        if dims.is_empty() {
+            Self::Int
+        } else {
+            Self::Array(dims)
+        }
+    }
+
+    /// Returns the type of the object used as a value, arrays decay into pointers.
+    pub fn decay(self) -> Self {
+        match self {
+            Self::Array(dims) => Self::Pointer(dims[1..].to_vec()),
+            ty => ty,
+        }
+    }
+
+    /// Returns the type of the element designated by a subscript, if the type can be subscripted.
+    pub fn index(&self) -> Option<Self> {
+        match self {
+            Self::Array(dims) => Some(Self::object(dims[1..].to_vec())),
+            Self::Pointer(dims) => Some(Self::object(dims.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// Types are displayed the way they are written in SysY.
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Void => write!(f, "void"),
+            Self::Int => write!(f, "int"),
+            Self::Array(dims) => {
+                write!(f, "int")?;
+                dims.iter().try_for_each(|dim| write!(f, "[{}]", dim))
+            }
+            Self::Pointer(dims) => {
+                write!(f, "int[]")?;
+                dims.iter().try_for_each(|dim| write!(f, "[{}]", dim))
+            }
+        }
+    }
+}
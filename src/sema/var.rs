@@ -0,0 +1,209 @@
+use crate::sema::{Analyze, ErrorKind, Evaluate, Result, SymbolKind, Type};
+use crate::sema::analyzer::Analyzer;
+use crate::ast::exp::{ConstExp, Exp};
+use crate::ast::var::*;
+
+impl<'ast> Analyze<'ast> for ConstDecl {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        for def in &self.defs {
+            let result = analyzer.located(def.span, |analyzer| def.analyze(analyzer));
+            analyzer.recover(result);
+        }
+        Ok(())
+    }
+}
+
+impl<'ast> Analyze<'ast> for ConstDef {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let dims = analyze_dims(&self.dims, analyzer)?;
+        let len = dims.iter().product();
+        let elems = flatten(&self.val, &dims).and_then(|elems| {
+            elems
+                .into_iter()
+                .map(|exp| exp.map_or(Ok(0), |exp| exp.analyze(analyzer)))
+                .collect::<Result<Vec<_>>>()
+        });
+        // the constant is defined even if its initializer is invalid,
+        // so that its uses are not reported again
+        let kind = SymbolKind::Const {
+            ty: Type::object(dims),
+            elems: elems.as_ref().map_or_else(|_| vec![0; len], Clone::clone),
+        };
+        analyzer.define(&self.id, self.span, kind)?;
+        elems.map(|_| ())
+    }
+}
+
+impl<'ast> Analyze<'ast> for Decl {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        for def in &self.defs {
+            let result = analyzer.located(def.span, |analyzer| def.analyze(analyzer));
+            analyzer.recover(result);
+        }
+        Ok(())
+    }
+}
+
+impl<'ast> Analyze<'ast> for Def {
+    type Out = ();
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        let dims = analyze_dims(&self.dims, analyzer)?;
+        let init = match &self.val {
+            None => Ok(None),
+            Some(val) => flatten(val, &dims).and_then(|elems| {
+                let global = analyzer.is_global();
+                let mut vals = Vec::new();
+                for exp in elems {
+                    match exp {
+                        Some(exp) => {
+                            exp.analyze_int(analyzer)?;
+                            // global variables are initialized by constants
+                            if global {
+                                vals.push(analyzer.located(exp.span, |analyzer| exp.evaluate(&analyzer.analysis))?);
+                            }
+                        }
+                        None => vals.push(0),
+                    }
+                }
+                Ok(global.then_some(vals))
+            }),
+        };
+        // the variable is defined even if its initializer is invalid,
+        // so that its uses are not reported again
+        let scalar = dims.is_empty();
+        let kind = SymbolKind::Var {
+            ty: Type::object(dims),
+            init: init.as_ref().ok().cloned().flatten(),
+        };
+        let id = analyzer.define(&self.id, self.span, kind)?;
+        // only scalar locals are tracked, arrays are often initialized element by element
+        if scalar && self.val.is_none() && !analyzer.is_global() {
+            analyzer.flow.declare(id);
+        }
+        init.map(|_| ())
+    }
+}
+
+impl<'ast> Analyze<'ast> for BType {
+    type Out = Type;
+
+    fn analyze(&'ast self, _: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        Ok(match self {
+            Self::Int => Type::Int,
+        })
+    }
+}
+
+impl<'ast> Analyze<'ast> for LVal {
+    /// The type of the designated object, before arrays decay.
+    type Out = Type;
+
+    fn analyze(&'ast self, analyzer: &mut Analyzer<'ast>) -> Result<Self::Out> {
+        analyzer.located(self.span, |analyzer| {
+            let symbol = analyzer.resolve(&self.id, self.span)?;
+            let mut ty = match &analyzer.symbol(symbol).kind {
+                SymbolKind::Const { ty, .. } | SymbolKind::Var { ty, .. } => ty.clone(),
+                SymbolKind::Function { .. } => return Err(ErrorKind::NotAVariable(self.id.clone()).into()),
+            };
+            for index in &self.indices {
+                index.analyze_int(analyzer)?;
+                ty = ty.index().ok_or_else(|| ErrorKind::InvalidSubscript(self.id.clone()))?;
+            }
+            Ok(ty)
+        })
+    }
+}
+
+/// Initializers that can be flattened by the SysY rules.
+pub trait Initializer: Sized {
+    type Elem;
+
+    fn split(&self) -> InitItem<'_, Self, Self::Elem>;
+}
+
+pub enum InitItem<'a, I, E> {
+    Exp(&'a E),
+    List(&'a [I]),
+}
+
+impl Initializer for InitVal {
+    type Elem = Exp;
+
+    fn split(&self) -> InitItem<'_, Self, Self::Elem> {
+        match self {
+            Self::Exp(exp) => InitItem::Exp(exp),
+            Self::List(list) => InitItem::List(list),
+        }
+    }
+}
+
+impl Initializer for ConstInitVal {
+    type Elem = ConstExp;
+
+    fn split(&self) -> InitItem<'_, Self, Self::Elem> {
+        match self {
+            Self::Exp(exp) => InitItem::Exp(exp),
+            Self::List(list) => InitItem::List(list),
+        }
+    }
+}
+
+/// Flattens the initializer of an object with the given dimensions into
+/// a row-major element list, `None` stands for the zero-padded elements.
+pub fn flatten<'a, I: Initializer>(init: &'a I, dims: &[usize]) -> Result<Vec<Option<&'a I::Elem>>> {
+    match init.split() {
+        InitItem::Exp(exp) if dims.is_empty() => Ok(vec![Some(exp)]),
+        InitItem::List(list) if !dims.is_empty() => flatten_list(list, dims),
+        _ => Err(ErrorKind::InvalidInitializer.into()),
+    }
+}
+
+fn flatten_list<'a, I: Initializer>(list: &'a [I], dims: &[usize]) -> Result<Vec<Option<&'a I::Elem>>> {
+    let len = dims.iter().product();
+    let mut elems = Vec::new();
+    for init in list {
+        match init.split() {
+            InitItem::Exp(exp) => elems.push(Some(exp)),
+            InitItem::List(list) => {
+                // a nested list initializes the largest sub-array aligned to the current position
+                let sub = (1..dims.len())
+                    .find(|&i| elems.len() % dims[i..].iter().product::<usize>() == 0)
+                    .ok_or(ErrorKind::InvalidInitializer)?;
+                elems.extend(flatten_list(list, &dims[sub..])?);
+            }
+        }
+        if elems.len() > len {
+            return Err(ErrorKind::InvalidInitializer.into());
+        }
+    }
+    elems.resize(len, None);
+    Ok(elems)
+}
+
+/// The largest size of an array in bytes, so that the offsets in it fit in an `int` on RV32.
+const MAX_ARRAY_SIZE: usize = i32::MAX as usize;
+
+pub fn analyze_dims<'ast>(dims: &'ast [ConstExp], analyzer: &mut Analyzer<'ast>) -> Result<Vec<usize>> {
+    // the size in bytes of the elements of the dimensions analyzed so far
+    let mut size = 4usize;
+    dims
+        .iter()
+        .map(|dim| match dim.analyze(analyzer)? {
+            len if len > 0 => {
+                size = size.saturating_mul(len as usize);
+                match size <= MAX_ARRAY_SIZE {
+                    true => Ok(len as usize),
+                    false => Err(ErrorKind::InvalidArraySize.at(dim.span)),
+                }
+            }
+            _ => Err(ErrorKind::InvalidArraySize.at(dim.span)),
+        })
+        .collect()
+}
@@ -0,0 +1,151 @@
+use crate::sema::SymbolId;
+use crate::source::Span;
+use core::fmt;
+use std::collections::HashSet;
+
+/// Categories of warnings, which can be switched on and off separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    Unused,
+    Shadow,
+    Uninitialized,
+    UnreachableCode,
+    DivByZero,
+}
+
+impl Lint {
+    const ALL: [Lint; 5] = [Self::Unused, Self::Shadow, Self::Uninitialized, Self::UnreachableCode, Self::DivByZero];
+
+    /// The name used by `-W<name>` and `-Wno-<name>`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unused => "unused",
+            Self::Shadow => "shadow",
+            Self::Uninitialized => "uninitialized",
+            Self::UnreachableCode => "unreachable-code",
+            Self::DivByZero => "div-by-zero",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+}
+
+pub struct Warning {
+    pub kind: WarningKind,
+    pub span: Span,
+    /// A related location with its explanation.
+    pub note: Option<(Span, &'static str)>,
+}
+
+pub enum WarningKind {
+    UnusedVariable(String),
+    UnusedParameter(String),
+    Shadow(String),
+    Uninitialized(String),
+    Unreachable,
+    /// Found by the optimizer once the divisor is known.
+    DivisionByZero,
+}
+
+impl WarningKind {
+    pub fn lint(&self) -> Lint {
+        match self {
+            Self::UnusedVariable(_) | Self::UnusedParameter(_) => Lint::Unused,
+            Self::Shadow(_) => Lint::Shadow,
+            Self::Uninitialized(_) => Lint::Uninitialized,
+            Self::Unreachable => Lint::UnreachableCode,
+            Self::DivisionByZero => Lint::DivByZero,
+        }
+    }
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnusedVariable(id) => write!(f, "unused variable `{}`", id),
+            Self::UnusedParameter(id) => write!(f, "unused parameter `{}`", id),
+            Self::Shadow(id) => write!(f, "declaration of `{}` shadows an outer declaration", id),
+            Self::Uninitialized(id) => write!(f, "`{}` may be used uninitialized", id),
+            Self::Unreachable => write!(f, "unreachable code"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+/// Which warnings are reported and whether they are errors, all are reported by default.
+#[derive(Default)]
+pub struct WarningOptions {
+    disabled: HashSet<Lint>,
+    pub error: bool,
+}
+
+impl WarningOptions {
+    /// Applies a `-W` flag, returns `false` if it is not recognized.
+    pub fn parse_flag(&mut self, flag: &str) -> bool {
+        let Some(name) = flag.strip_prefix("-W") else {
+            return false;
+        };
+        if name == "error" {
+            self.error = true;
+            return true;
+        }
+        match name.strip_prefix("no-") {
+            Some(name) => Lint::from_name(name).map(|lint| self.disabled.insert(lint)).is_some(),
+            None => Lint::from_name(name).map(|lint| self.disabled.remove(&lint)).is_some(),
+        }
+    }
+
+    pub fn enabled(&self, lint: Lint) -> bool {
+        !self.disabled.contains(&lint)
+    }
+}
+
+/// The state of the dataflow along the control flow of a function body,
+/// which finds the locals that may be read before they are initialized.
+#[derive(Clone)]
+pub enum Flow {
+    /// The scalar locals that may not be initialized yet on some path.
+    Reachable(HashSet<SymbolId>),
+    /// No path reaches here, `reported` once a warning says so.
+    Unreachable { reported: bool },
+}
+
+impl Flow {
+    pub fn entry() -> Self {
+        Self::Reachable(HashSet::new())
+    }
+
+    pub fn unreachable() -> Self {
+        Self::Unreachable { reported: false }
+    }
+
+    /// Merges the states of two paths into the same point.
+    pub fn join(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Reachable(mut lhs), Self::Reachable(rhs)) => {
+                lhs.extend(rhs);
+                Self::Reachable(lhs)
+            }
+            (Self::Reachable(uninit), _) | (_, Self::Reachable(uninit)) => Self::Reachable(uninit),
+            _ => Self::unreachable(),
+        }
+    }
+
+    pub fn declare(&mut self, id: SymbolId) {
+        if let Self::Reachable(uninit) = self {
+            uninit.insert(id);
+        }
+    }
+
+    pub fn initialize(&mut self, id: SymbolId) {
+        if let Self::Reachable(uninit) = self {
+            uninit.remove(&id);
+        }
+    }
+
+    pub fn may_be_uninitialized(&self, id: SymbolId) -> bool {
+        matches!(self, Self::Reachable(uninit) if uninit.contains(&id))
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+use std::ops::Range;
+
+/// A range of byte offsets in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+/// A line and column in the source text, both starting from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A file read by the preprocessor, the input file comes first.
+pub struct SourceFile {
+    pub name: String,
+    pub text: String,
+}
+
+/// Where a line of the preprocessed text comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Origin {
+    /// Index into the files read by the preprocessor.
+    pub file: usize,
+    /// The line in that file, starting from 1.
+    pub line: usize,
+}
+
+/// Maps byte offsets in the preprocessed text back to lines and columns, and
+/// those lines back to where they come from.
+pub struct SourceMap<'src> {
+    text: &'src str,
+    /// Byte offsets where each line starts.
+    line_starts: Vec<usize>,
+    /// The origin of each line of the text.
+    origins: &'src [Origin],
+    files: &'src [SourceFile],
+}
+
+impl<'src> SourceMap<'src> {
+    pub fn new(text: &'src str, origins: &'src [Origin], files: &'src [SourceFile]) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { text, line_starts, origins, files }
+    }
+
+    /// Returns the location of the byte offset, columns are counted in characters.
+    pub fn location(&self, offset: usize) -> Location {
+        let offset = offset.min(self.text.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let column = self.text[self.line_starts[line]..offset].chars().count() + 1;
+        Location { line: line + 1, column }
+    }
+
+    /// Returns the text of the line, without the line terminator.
+    pub fn line(&self, line: usize) -> &'src str {
+        let start = self.line_starts[line - 1];
+        let end = self.line_starts.get(line).copied().unwrap_or(self.text.len());
+        self.text[start..end].trim_end_matches(['\n', '\r'])
+    }
+
+    pub fn error(&self, span: Span, message: &dyn fmt::Display) -> String {
+        self.diagnostic("error", span, message)
+    }
+
+    pub fn warning(&self, span: Span, message: &dyn fmt::Display) -> String {
+        self.diagnostic("warning", span, message)
+    }
+
+    pub fn note(&self, span: Span, message: &dyn fmt::Display) -> String {
+        self.diagnostic("note", span, message)
+    }
+
+    /// Formats an error on the columns of a line of a source file, or on the whole line if
+    /// there are none, for errors found by the preprocessor.
+    pub fn error_at(&self, origin: Origin, columns: Option<Range<usize>>, message: &dyn fmt::Display) -> String {
+        let text = self.files[origin.file].text.lines().nth(origin.line - 1).unwrap_or("");
+        let (column, width) = match columns {
+            Some(columns) => (columns.start, columns.len().max(1)),
+            None => (text.len() - text.trim_start().len() + 1, text.trim().chars().count().max(1)),
+        };
+        self.format("error", origin, column, text, width, message)
+    }
+
+    /// Formats a diagnostic at the span, quoting its first line with a caret under the span.
+    /// After macro expansion the quoted line is the expanded one.
+    fn diagnostic(&self, level: &str, span: Span, message: &dyn fmt::Display) -> String {
+        let Location { line, column } = self.location(span.start);
+        let text = self.line(line);
+        let width = text
+            .chars()
+            .count()
+            .saturating_sub(column - 1)
+            .min(self.location(span.end).column.saturating_sub(column))
+            .max(1);
+        let origin = self.origins.get(line - 1).copied().unwrap_or(Origin { file: 0, line });
+        self.format(level, origin, column, text, width, message)
+    }
+
+    fn format(
+        &self,
+        level: &str,
+        origin: Origin,
+        column: usize,
+        text: &str,
+        width: usize,
+        message: &dyn fmt::Display,
+    ) -> String {
+        // keep tabs so that the caret lines up with the quoted text
+        let indent: String = text
+            .chars()
+            .take(column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        // lines of the input file are not prefixed by its name
+        let file = match origin.file {
+            0 => String::new(),
+            file => format!("{}:", self.files[file].name),
+        };
+        let line = origin.line;
+        let margin = " ".repeat(line.to_string().len());
+        format!(
+            "{}{}:{}: {}: {}\n{} |\n{} | {}\n{} | {}{}",
+            file, line, column, level, message, margin, line, text, margin, indent, "^".repeat(width),
+        )
+    }
+}